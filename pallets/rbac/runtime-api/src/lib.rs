@@ -0,0 +1,17 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries on the role-based access control policy which are too expensive to run on-chain.
+	pub trait RbacApi<AccountId, RoleName>
+	where
+		AccountId: Codec,
+		RoleName: Codec,
+	{
+		/// Returns every account holding mutually exclusive roles together with the offending
+		/// pairs of roles.
+		fn exclusive_role_violations() -> Vec<(AccountId, RoleName, RoleName)>;
+	}
+}
@@ -4,17 +4,25 @@ use super::*;
 
 #[allow(unused)]
 use crate::{
-	primitives::RoleDispatchOrigin, traits::GetCallMetadataIndecies, Call as RolesCall, Config,
-	Event, Pallet, Roles,
+	primitives::{PolicyChange, RoleDispatchOrigin, RoleMetadata, RoleSettings},
+	traits::GetCallMetadataIndecies,
+	Call as RolesCall, Config, Event, Pallet, Roles,
 };
 // use crate::tests_utils::
+use codec::{Decode, Encode};
 use frame_benchmarking::v2::*;
+use frame_support::traits::Currency;
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Hash, TrailingZeroInput};
 
 fn role_name_of<T: Config>(name: &[u8]) -> RoleNameOf<T> {
 	name.to_vec().try_into().expect("Expected to generate a role name")
 }
 
+fn policy_changes_of<T: Config>(change: PolicyChangeOf<T>) -> PolicyChangesOf<T> {
+	vec![change].try_into().expect("Expected to generate a batch of policy changes")
+}
+
 fn sample_call<T: Config>() -> Box<T::ExtendedRuntimeCall> {
 	let call: T::ExtendedRuntimeCall = Call::create_role {
 		role_name: vec![0u8].try_into().unwrap(),
@@ -45,6 +53,87 @@ fn sample_call_metadata<T: Config>() -> T::CallMetadata {
 	call.get_call_metadata_indicies().into()
 }
 
+fn approval_role_with_proposer<T: Config>() -> (RoleNameOf<T>, T::AccountId) {
+	let role_name = role_name_of::<T>(b"NoRole");
+	Pallet::<T>::create_role(
+		RawOrigin::Root.into(),
+		role_name.clone(),
+		false,
+		RoleDispatchOrigin::Regular,
+	)
+	.expect("Expected to create a role");
+	Pallet::<T>::add_call(RawOrigin::Root.into(), role_name.clone(), sample_call::<T>())
+		.expect("Expected to add a call to a role");
+	Pallet::<T>::set_approval_threshold(
+		RawOrigin::Root.into(),
+		role_name.clone(),
+		sample_call::<T>(),
+		Some(2),
+	)
+	.expect("Expected to set an approval threshold");
+	let proposer: T::AccountId = whitelisted_caller();
+	Pallet::<T>::assign_role(RawOrigin::Root.into(), proposer.clone(), role_name.clone())
+		.expect("Expected to assign a role");
+	T::Currency::make_free_balance_be(
+		&proposer,
+		T::Currency::minimum_balance().saturating_add(T::ProposalDeposit::get()),
+	);
+	(role_name, proposer)
+}
+
+fn group_with_member<T: Config>() -> (GroupNameOf<T>, T::AccountId) {
+	let group_name = role_name_of::<T>(b"NoGroup");
+	Pallet::<T>::create_group(RawOrigin::Root.into(), group_name.clone())
+		.expect("Expected to create a group");
+	let member: T::AccountId = whitelisted_caller();
+	Pallet::<T>::add_group_member(RawOrigin::Root.into(), group_name.clone(), member.clone())
+		.expect("Expected to add a group member");
+	(group_name, member)
+}
+
+fn role_with_invitation<T: Config>() -> (RoleNameOf<T>, T::AccountId) {
+	let role_name = role_name_of::<T>(b"NoRole");
+	Pallet::<T>::create_role(
+		RawOrigin::Root.into(),
+		role_name.clone(),
+		false,
+		RoleDispatchOrigin::Regular,
+	)
+	.expect("Expected to create a role");
+	let who: T::AccountId = whitelisted_caller();
+	Pallet::<T>::invite_to_role(RawOrigin::Root.into(), who.clone(), role_name.clone())
+		.expect("Expected to invite an account to a role");
+	(role_name, who)
+}
+
+/// Creates a role granted `count` calls, each with constraints and an approval threshold, so that
+/// rewriting its references visits the grants, the most expensive stage, and every other stage
+/// empty.
+fn role_with_grants<T: Config>(role_name: &RoleNameOf<T>, count: u32) {
+	Pallet::<T>::create_role(
+		RawOrigin::Root.into(),
+		role_name.clone(),
+		false,
+		RoleDispatchOrigin::Regular,
+	)
+	.expect("Expected to create a role");
+	for index in 0..count {
+		let call_metadata: T::CallMetadata = (u64::from(index), 0).into();
+		let mut call_roles = CallRolesListOf::<T>::default();
+		call_roles
+			.try_insert(role_name.clone())
+			.expect("Expected to grant a call to a role");
+		CallRoles::<T>::insert(&call_metadata, call_roles);
+		CallConstraints::<T>::insert(
+			&call_metadata,
+			role_name,
+			CallConstraintsListOf::<T>::default(),
+		);
+		ApprovalThresholds::<T>::insert(&call_metadata, role_name, 2);
+		Pallet::<T>::inc_role_consumers(role_name).expect("Expected to increase consumers counter");
+	}
+}
+
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
@@ -196,5 +285,1120 @@ mod benchmarks {
 		);
 	}
 
+	#[benchmark]
+	fn add_parent_role(n: Linear<0, { T::InheritanceChecksLimit::get() }>) {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let parent_role_name = role_name_of::<T>(b"ParentRole");
+		let excluded_role_name = role_name_of::<T>(b"Excluded");
+		for name in [&role_name, &parent_role_name, &excluded_role_name] {
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+		}
+		Pallet::<T>::add_exclusive_roles(
+			RawOrigin::Root.into(),
+			parent_role_name.clone(),
+			excluded_role_name,
+		)
+		.expect("Expected to make roles mutually exclusive");
+		for index in 0..n {
+			Pallet::<T>::assign_role(
+				RawOrigin::Root.into(),
+				account("holder", index, 0),
+				role_name.clone(),
+			)
+			.expect("Expected to assign a role");
+		}
+		for index in 0..T::ChildRolesLimit::get() {
+			let child_role_name = role_name_of::<T>(&index.to_le_bytes());
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				child_role_name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+			Pallet::<T>::add_parent_role(
+				RawOrigin::Root.into(),
+				child_role_name,
+				role_name.clone(),
+			)
+			.expect("Expected to add a parent role");
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), parent_role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::ParentRoleAdded {
+				role_name: role_name.clone(),
+				parent_role_name: parent_role_name.clone(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_parent_role() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let parent_role_name = role_name_of::<T>(b"ParentRole");
+		for name in [&role_name, &parent_role_name] {
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+		}
+		Pallet::<T>::add_parent_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			parent_role_name.clone(),
+		)
+		.expect("Expected to add a parent role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), parent_role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::ParentRoleRemoved {
+				role_name: role_name.clone(),
+				parent_role_name: parent_role_name.clone(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn assign_role_with_validity() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, whitelisted_caller(), role_name.clone(), None, Some(expires_at));
+		assert_last_event::<T>(
+			Event::<T>::AssignmentValiditySet {
+				role_name: role_name.clone(),
+				who: whitelisted_caller(),
+				validity: AssignmentValidity { not_before: None, expires_at: Some(expires_at) },
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn expire_assignment() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let expires_at = frame_system::Pallet::<T>::block_number().saturating_add(10u32.into());
+		Pallet::<T>::assign_role_with_validity(
+			RawOrigin::Root.into(),
+			caller.clone(),
+			role_name.clone(),
+			None,
+			Some(expires_at),
+		)
+		.expect("Expected to assign a role");
+
+		#[block]
+		{
+			Pallet::<T>::expire_assignment(expires_at, &caller, &role_name);
+		}
+		assert_last_event::<T>(
+			Event::<T>::AssignmentExpired { role_name: role_name.clone(), who: caller }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_role_admin() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let admin_role_name = role_name_of::<T>(b"AdminRole");
+		let previous_admin_role_name = role_name_of::<T>(b"PreviousAdminRole");
+		for name in [&role_name, &admin_role_name, &previous_admin_role_name] {
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+		}
+		Pallet::<T>::set_role_admin(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			Some(previous_admin_role_name),
+		)
+		.expect("Expected to set an admin role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), Some(admin_role_name.clone()));
+		assert_last_event::<T>(
+			Event::<T>::RoleAdminSet { role_name, admin_role_name: Some(admin_role_name) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn add_pallet_wildcard() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let (pallet_index, _) = sample_call::<T>().get_call_metadata_indicies();
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), pallet_index);
+		assert_last_event::<T>(
+			Event::<T>::PalletWildcardAddedToRole { role_name, pallet_index }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_pallet_wildcard() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let (pallet_index, _) = sample_call::<T>().get_call_metadata_indicies();
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::add_pallet_wildcard(RawOrigin::Root.into(), role_name.clone(), pallet_index)
+			.expect("Expected to add a pallet wildcard to a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), pallet_index);
+		assert_last_event::<T>(
+			Event::<T>::PalletWildcardRemovedFromRole { role_name, pallet_index }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_call_constraints() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::add_call(RawOrigin::Root.into(), role_name.clone(), sample_call::<T>())
+			.expect("Expected to add a call to a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), sample_call::<T>(), Default::default());
+		assert_last_event::<T>(
+			Event::<T>::CallConstraintsSet {
+				role_name,
+				call_metadata: sample_call_metadata::<T>(),
+				constraints: Default::default(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_role_rate_limit() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let rate_limit = RateLimit { max_calls: 10, period: 100, scope: RateLimitScope::Global };
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), Some(rate_limit));
+		assert_last_event::<T>(
+			Event::<T>::RoleRateLimitSet { role_name, rate_limit: Some(rate_limit) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_role_budget() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let budget = SpendingBudget { limit: 1_000u32.into(), period: 100 };
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), Some(budget.clone()));
+		assert_last_event::<T>(
+			Event::<T>::RoleBudgetSet { role_name, budget: Some(budget) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn add_role_denial() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), sample_call::<T>());
+		assert_last_event::<T>(
+			Event::<T>::CallDeniedToRole { role_name, call_metadata: sample_call_metadata::<T>() }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_role_denial() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::add_role_denial(RawOrigin::Root.into(), role_name.clone(), sample_call::<T>())
+			.expect("Expected to deny a call to a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), sample_call::<T>());
+		assert_last_event::<T>(
+			Event::<T>::CallDenialRemovedFromRole {
+				role_name,
+				call_metadata: sample_call_metadata::<T>(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn add_account_denial() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, whitelisted_caller(), sample_call::<T>());
+		assert_last_event::<T>(
+			Event::<T>::CallDeniedToAccount {
+				who: whitelisted_caller(),
+				call_metadata: sample_call_metadata::<T>(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_account_denial() {
+		Pallet::<T>::add_account_denial(
+			RawOrigin::Root.into(),
+			whitelisted_caller(),
+			sample_call::<T>(),
+		)
+		.expect("Expected to deny a call to an account");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, whitelisted_caller(), sample_call::<T>());
+		assert_last_event::<T>(
+			Event::<T>::CallDenialRemovedFromAccount {
+				who: whitelisted_caller(),
+				call_metadata: sample_call_metadata::<T>(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_enforcement_mode() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, EnforcementMode::DefaultDeny);
+		assert_last_event::<T>(
+			Event::<T>::EnforcementModeSet { mode: EnforcementMode::DefaultDeny }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn add_public_call() {
+		#[extrinsic_call]
+		_(RawOrigin::Root, sample_call::<T>());
+		assert_last_event::<T>(
+			Event::<T>::PublicCallAdded { call_metadata: sample_call_metadata::<T>() }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_public_call() {
+		Pallet::<T>::add_public_call(RawOrigin::Root.into(), sample_call::<T>())
+			.expect("Expected to make a call public");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, sample_call::<T>());
+		assert_last_event::<T>(
+			Event::<T>::PublicCallRemoved { call_metadata: sample_call_metadata::<T>() }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn add_exclusive_roles() {
+		let role_name = role_name_of::<T>(b"Payer");
+		let other_role_name = role_name_of::<T>(b"Approver");
+		for name in [&role_name, &other_role_name] {
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), other_role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::ExclusiveRolesAdded { role_name, other_role_name }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn remove_exclusive_roles() {
+		let role_name = role_name_of::<T>(b"Payer");
+		let other_role_name = role_name_of::<T>(b"Approver");
+		for name in [&role_name, &other_role_name] {
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+		}
+		Pallet::<T>::add_exclusive_roles(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			other_role_name.clone(),
+		)
+		.expect("Expected to make roles mutually exclusive");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), other_role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::ExclusiveRolesRemoved { role_name, other_role_name }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_approval_threshold() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::add_call(RawOrigin::Root.into(), role_name.clone(), sample_call::<T>())
+			.expect("Expected to add a call to a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), sample_call::<T>(), Some(2));
+		assert_last_event::<T>(
+			Event::<T>::ApprovalThresholdSet {
+				role_name,
+				call_metadata: sample_call_metadata::<T>(),
+				threshold: Some(2),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn propose_call_with_role() {
+		let (role_name, proposer) = approval_role_with_proposer::<T>();
+		let call_hash = T::Hashing::hash(&sample_call::<T>().encode());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer.clone()), sample_call::<T>(), role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::CallProposed {
+				role_name,
+				who: proposer,
+				call_hash,
+				call_metadata: sample_call_metadata::<T>(),
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn approve_call() {
+		let (role_name, proposer) = approval_role_with_proposer::<T>();
+		let call_hash = T::Hashing::hash(&sample_call::<T>().encode());
+		Pallet::<T>::propose_call_with_role(
+			RawOrigin::Signed(proposer).into(),
+			sample_call::<T>(),
+			role_name.clone(),
+		)
+		.expect("Expected to propose a call");
+		let approver: T::AccountId = account("approver", 0, 0);
+		Pallet::<T>::assign_role(RawOrigin::Root.into(), approver.clone(), role_name.clone())
+			.expect("Expected to assign a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(approver), role_name.clone(), call_hash, Weight::MAX);
+		assert!(Pallet::<T>::proposal(role_name, call_hash).is_none());
+	}
+
+	#[benchmark]
+	fn cancel_proposal() {
+		let (role_name, proposer) = approval_role_with_proposer::<T>();
+		let call_hash = T::Hashing::hash(&sample_call::<T>().encode());
+		Pallet::<T>::propose_call_with_role(
+			RawOrigin::Signed(proposer.clone()).into(),
+			sample_call::<T>(),
+			role_name.clone(),
+		)
+		.expect("Expected to propose a call");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer), role_name.clone(), call_hash);
+		assert_last_event::<T>(Event::<T>::ProposalCancelled { role_name, call_hash }.into());
+	}
+
+	#[benchmark]
+	fn remove_expired_proposal() {
+		let (role_name, proposer) = approval_role_with_proposer::<T>();
+		let call_hash = T::Hashing::hash(&sample_call::<T>().encode());
+		Pallet::<T>::propose_call_with_role(
+			RawOrigin::Signed(proposer.clone()).into(),
+			sample_call::<T>(),
+			role_name.clone(),
+		)
+		.expect("Expected to propose a call");
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::ProposalTimeout::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(proposer), role_name.clone(), call_hash);
+		assert_last_event::<T>(Event::<T>::ProposalTimedOut { role_name, call_hash }.into());
+	}
+
+	#[benchmark]
+	fn accept_impersonation() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::SignedAs { who: caller.clone() },
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), role_name.clone());
+		assert_last_event::<T>(Event::<T>::ImpersonationAccepted { role_name, who: caller }.into());
+	}
+
+	#[benchmark]
+	fn revoke_impersonation() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::SignedAs { who: caller.clone() },
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::accept_impersonation(
+			RawOrigin::Signed(caller.clone()).into(),
+			role_name.clone(),
+		)
+		.expect("Expected to accept an impersonation");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), role_name.clone());
+		assert_last_event::<T>(Event::<T>::ImpersonationRevoked { role_name, who: caller }.into());
+	}
+
+	#[benchmark]
+	fn create_group() {
+		let group_name = role_name_of::<T>(b"NoGroup");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, group_name.clone());
+		assert_last_event::<T>(Event::<T>::GroupCreated { group_name }.into());
+	}
+
+	#[benchmark]
+	fn remove_group() {
+		let group_name = role_name_of::<T>(b"NoGroup");
+		Pallet::<T>::create_group(RawOrigin::Root.into(), group_name.clone())
+			.expect("Expected to create a group");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, group_name.clone());
+		assert_last_event::<T>(Event::<T>::GroupRemoved { group_name }.into());
+	}
+
+	#[benchmark]
+	fn add_group_member() {
+		let group_name = role_name_of::<T>(b"NoGroup");
+		Pallet::<T>::create_group(RawOrigin::Root.into(), group_name.clone())
+			.expect("Expected to create a group");
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::assign_role_to_group(RawOrigin::Root.into(), group_name.clone(), role_name)
+			.expect("Expected to assign a role to a group");
+		let who: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, group_name.clone(), who.clone());
+		assert_last_event::<T>(Event::<T>::AccountAddedToGroup { group_name, who }.into());
+	}
+
+	#[benchmark]
+	fn remove_group_member() {
+		let (group_name, who) = group_with_member::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, group_name.clone(), who.clone());
+		assert_last_event::<T>(Event::<T>::AccountRemovedFromGroup { group_name, who }.into());
+	}
+
+	#[benchmark]
+	fn assign_role_to_group() {
+		let (group_name, _) = group_with_member::<T>();
+		for index in 1..T::MaxGroupMembers::get() {
+			Pallet::<T>::add_group_member(
+				RawOrigin::Root.into(),
+				group_name.clone(),
+				account("member", index, 0),
+			)
+			.expect("Expected to add a group member");
+		}
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, group_name.clone(), role_name.clone());
+		assert_last_event::<T>(Event::<T>::GroupAssignedToRole { role_name, group_name }.into());
+	}
+
+	#[benchmark]
+	fn unassign_role_from_group() {
+		let (group_name, _) = group_with_member::<T>();
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::assign_role_to_group(
+			RawOrigin::Root.into(),
+			group_name.clone(),
+			role_name.clone(),
+		)
+		.expect("Expected to assign a role to a group");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, group_name.clone(), role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::GroupUnassignedFromRole { role_name, group_name }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn assign_role_with_scope() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let scope = ResourceOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.expect("Expected to decode a resource from zeroes");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, whitelisted_caller(), role_name.clone(), scope.clone());
+		assert_last_event::<T>(
+			Event::<T>::AssignmentScopeSet { role_name, who: whitelisted_caller(), scope }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_role_membership_rule() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let rule = MembershipRuleOf::<T>::decode(&mut TrailingZeroInput::zeroes())
+			.expect("Expected to decode a membership rule from zeroes");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), Some(rule.clone()));
+		assert_last_event::<T>(
+			Event::<T>::RoleMembershipRuleSet { role_name, rule: Some(rule) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn invite_to_role() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let expires_at =
+			frame_system::Pallet::<T>::block_number().saturating_add(T::InvitationTimeout::get());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, whitelisted_caller(), role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::RoleInvitationSent { role_name, who: whitelisted_caller(), expires_at }
+				.into(),
+		);
+	}
+
+	#[benchmark]
+	fn accept_role_invitation() {
+		let (role_name, who) = role_with_invitation::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), role_name.clone());
+		assert_last_event::<T>(Event::<T>::RoleInvitationAccepted { role_name, who }.into());
+	}
+
+	#[benchmark]
+	fn decline_role_invitation() {
+		let (role_name, who) = role_with_invitation::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(who.clone()), role_name.clone());
+		assert_last_event::<T>(Event::<T>::RoleInvitationDeclined { role_name, who }.into());
+	}
+
+	#[benchmark]
+	fn cancel_role_invitation() {
+		let (role_name, who) = role_with_invitation::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, who.clone(), role_name.clone());
+		assert_last_event::<T>(Event::<T>::RoleInvitationCancelled { role_name, who }.into());
+	}
+
+	#[benchmark]
+	fn remove_expired_invitation() {
+		let (role_name, who) = role_with_invitation::<T>();
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::InvitationTimeout::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(whitelisted_caller()), who.clone(), role_name.clone());
+		assert_last_event::<T>(Event::<T>::RoleInvitationExpired { role_name, who }.into());
+	}
+
+	#[benchmark]
+	fn renounce_role() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let caller: T::AccountId = whitelisted_caller();
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::assign_role(RawOrigin::Root.into(), caller.clone(), role_name.clone())
+			.expect("Expected to assign a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), role_name.clone());
+		assert_last_event::<T>(
+			Event::<T>::AccountUnassignedFromRole { role_name, who: caller }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn renounce_all_roles() {
+		let caller: T::AccountId = whitelisted_caller();
+		for index in 0..T::RolesPerAccountLimit::get() {
+			let mut name = b"NoRole".to_vec();
+			name.extend(index.to_le_bytes());
+			let role_name = role_name_of::<T>(&name);
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				role_name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+			Pallet::<T>::assign_role(RawOrigin::Root.into(), caller.clone(), role_name)
+				.expect("Expected to assign a role");
+		}
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()));
+		assert!(Pallet::<T>::account_roles(&caller).unwrap_or_default().is_empty());
+	}
+
+	#[benchmark]
+	fn suspend_role() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone());
+		assert_last_event::<T>(Event::<T>::RoleSuspended { role_name }.into());
+	}
+
+	#[benchmark]
+	fn resume_role() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::suspend_role(RawOrigin::Root.into(), role_name.clone())
+			.expect("Expected to suspend a role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone());
+		assert_last_event::<T>(Event::<T>::RoleResumed { role_name }.into());
+	}
+
+	#[benchmark]
+	fn pause_role_dispatch() {
+		let until = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, true, Some(until));
+		assert_last_event::<T>(
+			Event::<T>::RoleDispatchPaused { block_extension: true, until: Some(until) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn unpause_role_dispatch() {
+		Pallet::<T>::pause_role_dispatch(RawOrigin::Root.into(), true, None)
+			.expect("Expected to pause role-based dispatch");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root);
+		assert_last_event::<T>(Event::<T>::RoleDispatchUnpaused.into());
+	}
+
+	#[benchmark]
+	fn force_remove_role(n: Linear<0, { T::RemovalStepsLimit::get() }>) {
+		let role_name = role_name_of::<T>(b"NoRole");
+		role_with_grants::<T>(&role_name, n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone());
+		assert!(
+			Pallet::<T>::role_removal(&role_name).is_some() ||
+				Pallet::<T>::roles(&role_name).is_none()
+		);
+	}
+
+	#[benchmark]
+	fn rename_role(n: Linear<0, { T::RemovalStepsLimit::get() }>) {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let new_role_name = role_name_of::<T>(b"NewRole");
+		role_with_grants::<T>(&role_name, n);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), new_role_name.clone());
+		assert!(
+			Pallet::<T>::role_rename(&role_name).is_some() ||
+				Pallet::<T>::roles(&new_role_name).is_some()
+		);
+	}
+
+	#[benchmark]
+	fn cancel_role_rename(n: Linear<0, { T::RemovalStepsLimit::get() }>) {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let new_role_name = role_name_of::<T>(b"NewRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		role_with_grants::<T>(&new_role_name, n);
+		RoleRenames::<T>::insert(
+			&role_name,
+			RoleRename { new_role_name: new_role_name.clone(), cursor: Default::default() },
+		);
+		RoleRenameSources::<T>::insert(&new_role_name, &role_name);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone());
+		assert!(
+			Pallet::<T>::role_rename(&new_role_name).is_some() ||
+				Pallet::<T>::roles(&new_role_name).is_none()
+		);
+	}
+
+	#[benchmark]
+	fn update_role() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let new_settings = RoleSettings {
+			allow_filter_bypassing: true,
+			dispatch_origin: RoleDispatchOrigin::Root,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), new_settings.clone());
+		assert_last_event::<T>(
+			Event::<T>::RoleUpdated {
+				role_name,
+				old_settings: RoleSettings {
+					allow_filter_bypassing: false,
+					dispatch_origin: RoleDispatchOrigin::Regular,
+				},
+				new_settings,
+			}
+			.into(),
+		);
+	}
+
+	#[benchmark]
+	fn set_role_metadata() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let mut tags = RoleTagsListOf::<T>::default();
+		for index in 0..T::RoleTagsLimit::get() {
+			let tag = vec![index as u8; T::RoleTagLengthLimit::get() as usize];
+			tags.try_insert(tag.try_into().expect("Expected to generate a role tag"))
+				.expect("Expected to add a role tag");
+		}
+		let metadata = RoleMetadata {
+			description: vec![0u8; T::RoleDescriptionLimit::get() as usize]
+				.try_into()
+				.expect("Expected to generate a role description"),
+			owner: Some(whitelisted_caller()),
+			uri: vec![0u8; T::RoleUriLimit::get() as usize]
+				.try_into()
+				.expect("Expected to generate a role URI"),
+			tags,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name.clone(), Some(metadata.clone()));
+		assert_last_event::<T>(
+			Event::<T>::RoleMetadataSet { role_name, metadata: Some(metadata) }.into(),
+		);
+	}
+
+	#[benchmark]
+	fn apply_create_role_change() {
+		let changes = policy_changes_of::<T>(PolicyChange::CreateRole {
+			role_name: role_name_of::<T>(b"NoRole"),
+			allow_filter_bypassing: false,
+			allow_dispatch_as: RoleDispatchOrigin::Regular,
+		});
+
+		#[extrinsic_call]
+		apply_policy_changes(RawOrigin::Root, changes);
+		assert_last_event::<T>(Event::<T>::PolicyChangesApplied { count: 1 }.into());
+	}
+
+	#[benchmark]
+	fn apply_add_call_change() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let changes =
+			policy_changes_of::<T>(PolicyChange::AddCall { role_name, call: sample_call::<T>() });
+
+		#[extrinsic_call]
+		apply_policy_changes(RawOrigin::Root, changes);
+		assert_last_event::<T>(Event::<T>::PolicyChangesApplied { count: 1 }.into());
+	}
+
+	#[benchmark]
+	fn apply_remove_call_change() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::add_call(RawOrigin::Root.into(), role_name.clone(), sample_call::<T>())
+			.expect("Expected to add a call to a role");
+		let changes = policy_changes_of::<T>(PolicyChange::RemoveCall {
+			role_name,
+			call: sample_call::<T>(),
+		});
+
+		#[extrinsic_call]
+		apply_policy_changes(RawOrigin::Root, changes);
+		assert_last_event::<T>(Event::<T>::PolicyChangesApplied { count: 1 }.into());
+	}
+
+	#[benchmark]
+	fn apply_assign_role_change() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		let changes = policy_changes_of::<T>(PolicyChange::AssignRole {
+			who: whitelisted_caller(),
+			role_name,
+		});
+
+		#[extrinsic_call]
+		apply_policy_changes(RawOrigin::Root, changes);
+		assert_last_event::<T>(Event::<T>::PolicyChangesApplied { count: 1 }.into());
+	}
+
+	#[benchmark]
+	fn apply_unassign_role_change() {
+		let role_name = role_name_of::<T>(b"NoRole");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::assign_role(RawOrigin::Root.into(), whitelisted_caller(), role_name.clone())
+			.expect("Expected to assign a role");
+		let changes = policy_changes_of::<T>(PolicyChange::UnassignRole {
+			who: whitelisted_caller(),
+			role_name,
+		});
+
+		#[extrinsic_call]
+		apply_policy_changes(RawOrigin::Root, changes);
+		assert_last_event::<T>(Event::<T>::PolicyChangesApplied { count: 1 }.into());
+	}
+
+	#[benchmark]
+	fn clone_role(n: Linear<0, { T::RemovalStepsLimit::get() }>) {
+		let role_name = role_name_of::<T>(b"NoRole");
+		let new_role_name = role_name_of::<T>(b"NewRole");
+		role_with_grants::<T>(&role_name, n);
+		for index in 0..T::ExclusiveRolesLimit::get() {
+			let other_role_name = role_name_of::<T>(&index.to_le_bytes());
+			Pallet::<T>::create_role(
+				RawOrigin::Root.into(),
+				other_role_name.clone(),
+				false,
+				RoleDispatchOrigin::Regular,
+			)
+			.expect("Expected to create a role");
+			Pallet::<T>::add_exclusive_roles(
+				RawOrigin::Root.into(),
+				role_name.clone(),
+				other_role_name,
+			)
+			.expect("Expected to make roles mutually exclusive");
+		}
+		let admin_role_name = role_name_of::<T>(b"Admin");
+		Pallet::<T>::create_role(
+			RawOrigin::Root.into(),
+			admin_role_name.clone(),
+			false,
+			RoleDispatchOrigin::Regular,
+		)
+		.expect("Expected to create a role");
+		Pallet::<T>::set_role_admin(
+			RawOrigin::Root.into(),
+			role_name.clone(),
+			Some(admin_role_name),
+		)
+		.expect("Expected to set the admin role");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, role_name, new_role_name.clone(), true);
+		assert!(Pallet::<T>::roles(&new_role_name).is_some());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 }
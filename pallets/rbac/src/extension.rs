@@ -1,14 +1,16 @@
-use crate::{traits::GetCallMetadataIndecies, CallValidator, Config, Pallet};
+use crate::{Config, Pallet};
 use codec::{Decode, Encode};
 use frame_support::dispatch::fmt::Debug;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{DispatchInfoOf, SignedExtension},
-	transaction_validity::TransactionValidityError,
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
 };
 use sp_std::marker::PhantomData;
 
-/// A structure to validate transactions based on user's role during the pre-dispatch phase.
+/// A structure to validate transactions based on user's role. Transactions are checked when
+/// they enter the pool, without counting them against rate limits, and again during the
+/// pre-dispatch phase, where they are counted.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct CheckRole<T: Config>(PhantomData<T>);
@@ -36,6 +38,18 @@ impl<T: Config + Send + Sync> SignedExtension for CheckRole<T> {
 		Ok(())
 	}
 
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		let allowing_roles = Pallet::<T>::validate_call(call, who)?;
+		Pallet::<T>::ensure_any_role_usable(&allowing_roles, who)?;
+		Ok(ValidTransaction::default())
+	}
+
 	fn pre_dispatch(
 		self,
 		who: &Self::AccountId,
@@ -43,8 +57,8 @@ impl<T: Config + Send + Sync> SignedExtension for CheckRole<T> {
 		_info: &DispatchInfoOf<Self::Call>,
 		_len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
-		Pallet::<T>::validate_by_metadata(call_metadata, who)?;
+		let allowing_roles = Pallet::<T>::validate_call(call, who)?;
+		Pallet::<T>::use_any_role(&allowing_roles, who)?;
 		Ok(())
 	}
 }
@@ -2,21 +2,33 @@
 
 pub use crate::{
 	extension::CheckRole,
-	primitives::{RoleDispatchOrigin, RoleInfo},
-	traits::CallValidator,
+	primitives::{
+		AssignmentValidity, CallProposal, EnforcementMode, ImpersonationConsent, PauseState,
+		PolicyChange, RateLimit, RateLimitScope, RoleClone, RoleDispatchOrigin, RoleInfo,
+		RoleMetadata, RoleRename, RoleRewriteCursor, RoleRewriteStage, RoleSettings,
+		SpendingBudget, SpendingWindow, UsageWindow, CALL_DENIED, DISPATCH_PAUSED,
+		RATE_LIMIT_EXCEEDED, ROLE_SUSPENDED,
+	},
+	traits::{CallConstraint, CallSpending, CallValidator, MembershipRule, ResourceExtractor},
 };
 pub use pallet::*;
 
+use crate::traits::GetCallMetadataIndecies;
 use codec::{FullCodec, MaxEncodedLen};
-use frame_support::{ensure, pallet_prelude::DispatchResult};
-use frame_system::Pallet as System;
+use frame_support::{
+	dispatch::{extract_actual_weight, DispatchResultWithPostInfo},
+	ensure,
+	pallet_prelude::{DispatchResult, Weight},
+	traits::{ConstU32, Currency, EnsureOrigin, Get, PalletInfoAccess, ReservableCurrency},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, Pallet as System};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::Zero,
+	traits::{CheckedAdd, Hash, One, Saturating, Zero},
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
-	BoundedBTreeSet, BoundedVec, DispatchError,
+	BoundedBTreeMap, BoundedBTreeSet, BoundedVec, DispatchError,
 };
-use sp_std::default::Default;
+use sp_std::{boxed::Box, collections::btree_set::BTreeSet, default::Default, vec, vec::Vec};
 pub use weights::*;
 
 #[cfg(test)]
@@ -33,14 +45,57 @@ mod benchmarking;
 pub mod weights;
 
 pub mod extension;
+pub mod migrations;
 pub mod primitives;
 pub mod traits;
 
 type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 type RoleNameOf<T> = BoundedVec<u8, <T as Config>::RoleNameLengthLimit>;
 type RoleInfoOf<T> = RoleInfo<<T as frame_system::Config>::AccountId>;
+type RoleSettingsOf<T> = RoleSettings<<T as frame_system::Config>::AccountId>;
+type RoleDescriptionOf<T> = BoundedVec<u8, <T as Config>::RoleDescriptionLimit>;
+type RoleUriOf<T> = BoundedVec<u8, <T as Config>::RoleUriLimit>;
+type RoleTagOf<T> = BoundedVec<u8, <T as Config>::RoleTagLengthLimit>;
+type RoleTagsListOf<T> = BoundedBTreeSet<RoleTagOf<T>, <T as Config>::RoleTagsLimit>;
+type RoleMetadataOf<T> =
+	RoleMetadata<AccountIdOf<T>, RoleDescriptionOf<T>, RoleUriOf<T>, RoleTagsListOf<T>>;
 type CallRolesListOf<T> = BoundedBTreeSet<RoleNameOf<T>, <T as Config>::RolesPerCallLimit>;
 type AccountRolesListOf<T> = BoundedBTreeSet<RoleNameOf<T>, <T as Config>::RolesPerAccountLimit>;
+type ParentRolesListOf<T> = BoundedBTreeSet<RoleNameOf<T>, <T as Config>::ParentRolesLimit>;
+type ChildRolesListOf<T> = BoundedBTreeSet<RoleNameOf<T>, <T as Config>::ChildRolesLimit>;
+type ExclusiveRolesListOf<T> = BoundedBTreeSet<RoleNameOf<T>, <T as Config>::ExclusiveRolesLimit>;
+type GroupNameOf<T> = BoundedVec<u8, <T as Config>::RoleNameLengthLimit>;
+type GroupMembersListOf<T> = BoundedBTreeSet<AccountIdOf<T>, <T as Config>::MaxGroupMembers>;
+type AccountGroupsListOf<T> = BoundedBTreeSet<GroupNameOf<T>, <T as Config>::GroupsPerAccountLimit>;
+type GroupRolesListOf<T> = BoundedBTreeSet<RoleNameOf<T>, <T as Config>::RolesPerGroupLimit>;
+type CallConstraintOf<T> = <<T as Config>::ExtendedRuntimeCall as CallConstraint>::Constraint;
+type CallConstraintsListOf<T> =
+	BoundedVec<CallConstraintOf<T>, <T as Config>::ConstraintsPerCallLimit>;
+type ResourceOf<T> = <<T as Config>::ExtendedRuntimeCall as ResourceExtractor>::Resource;
+type AssignmentScopesListOf<T> =
+	BoundedBTreeSet<ResourceOf<T>, <T as Config>::ScopesPerAssignmentLimit>;
+type MembershipRuleOf<T> = <<T as Config>::MembershipRules as MembershipRule<AccountIdOf<T>>>::Rule;
+type RoleMembershipRulesOf<T> =
+	BoundedBTreeMap<RoleNameOf<T>, MembershipRuleOf<T>, <T as Config>::MaxRuleBasedRoles>;
+type UsageWindowOf<T> = UsageWindow<BlockNumberFor<T>>;
+type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+type SpendingBudgetOf<T> = SpendingBudget<BalanceOf<T>>;
+type SpendingWindowOf<T> = SpendingWindow<BalanceOf<T>, BlockNumberFor<T>>;
+type AssignmentValidityOf<T> = AssignmentValidity<BlockNumberFor<T>>;
+type PauseStateOf<T> = PauseState<BlockNumberFor<T>>;
+type RawStorageKey = BoundedVec<u8, ConstU32<512>>;
+type RoleRewriteCursorOf = RoleRewriteCursor<RawStorageKey>;
+type RoleRenameOf<T> = RoleRename<RoleNameOf<T>, RawStorageKey>;
+type RoleCloneOf<T> = RoleClone<RoleNameOf<T>, RawStorageKey>;
+type PolicyChangeOf<T> =
+	PolicyChange<AccountIdOf<T>, RoleNameOf<T>, Box<<T as Config>::ExtendedRuntimeCall>>;
+type PolicyChangesOf<T> = BoundedVec<PolicyChangeOf<T>, <T as Config>::MaxPolicyChanges>;
+type ExpiringAssignmentsOf<T> =
+	BoundedVec<(AccountIdOf<T>, RoleNameOf<T>), <T as Config>::MaxExpiriesPerBlock>;
+type EncodedCallOf<T> = BoundedVec<u8, <T as Config>::MaxProposedCallLength>;
+type ApprovalsOf<T> = BoundedBTreeSet<AccountIdOf<T>, <T as Config>::MaxApprovals>;
+type CallProposalOf<T> =
+	CallProposal<AccountIdOf<T>, BlockNumberFor<T>, BalanceOf<T>, EncodedCallOf<T>, ApprovalsOf<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -49,7 +104,6 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::{
 			fmt::Debug, Dispatchable, GetDispatchInfo, PostDispatchInfo, UnfilteredDispatchable,
-			Vec,
 		},
 		pallet_prelude::*,
 		traits::{BuildGenesisConfig, Get},
@@ -58,7 +112,11 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use sp_std::boxed::Box;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -67,22 +125,96 @@ pub mod pallet {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Defines who can manage roles
 		type ManageOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Defines who can pause and unpause role-based dispatch in an emergency.
+		type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Defines the limit for the length of role names.
 		type RoleNameLengthLimit: Get<u32>;
+		/// Defines the limit for the length of role descriptions.
+		type RoleDescriptionLimit: Get<u32>;
+		/// Defines the limit for the length of the URIs of role policy documents.
+		type RoleUriLimit: Get<u32>;
+		/// Defines the limit for the length of role tags.
+		type RoleTagLengthLimit: Get<u32>;
+		/// Defines the maximum number of tags a single role can have.
+		type RoleTagsLimit: Get<u32>;
 		/// Defines the maximum number of roles that can be associated with a particular call.
 		type RolesPerCallLimit: Get<u32>;
+		/// Defines the maximum number of constraints attached to a role's permission to execute a
+		/// particular call.
+		type ConstraintsPerCallLimit: Get<u32>;
 		/// Defines the maximum number of roles that can be associated with a particular account.
 		type RolesPerAccountLimit: Get<u32>;
+		/// Defines the maximum number of resources a single role assignment can be scoped to.
+		type ScopesPerAssignmentLimit: Get<u32>;
+		/// Defines the maximum number of parent roles a single role can inherit permissions from.
+		type ParentRolesLimit: Get<u32>;
+		/// Defines the maximum number of roles that can inherit permissions directly from a single
+		/// role.
+		type ChildRolesLimit: Get<u32>;
+		/// Defines the maximum depth of the role hierarchy, i.e. the maximum number of parent links
+		/// that are followed when resolving inherited permissions. Links further up are ignored.
+		type MaxRoleHierarchyDepth: Get<u32>;
+		/// Defines the maximum number of role assignments that can expire at the same block.
+		type MaxExpiriesPerBlock: Get<u32>;
+		/// Defines the maximum number of roles a single role can be mutually exclusive with.
+		type ExclusiveRolesLimit: Get<u32>;
+		/// Defines the maximum number of accounts a single group can have as members. Group names
+		/// share the length limit of role names.
+		type MaxGroupMembers: Get<u32>;
+		/// Defines the maximum number of groups a single account can be a member of.
+		type GroupsPerAccountLimit: Get<u32>;
+		/// Defines the maximum number of roles that can be assigned to a single group.
+		type RolesPerGroupLimit: Get<u32>;
+		/// Defines the maximum number of accounts and groups holding a role or its descendants
+		/// that are checked for exclusive roles when the role inherits from a role with exclusive
+		/// roles. Inheriting fails if there are more of them.
+		type InheritanceChecksLimit: Get<u32>;
+		/// Evaluates the membership rules of roles held by every account satisfying them.
+		type MembershipRules: MembershipRule<Self::AccountId>;
+		/// Defines the maximum number of roles with a membership rule.
+		type MaxRuleBasedRoles: Get<u32>;
+		/// Defines the maximum number of storage entries visited by a single call forcing the
+		/// removal of, renaming or cloning a role.
+		type RemovalStepsLimit: Get<u32>;
+		/// Defines the maximum number of policy changes applied together by a single call.
+		type MaxPolicyChanges: Get<u32>;
 		/// Type representing the weight of this pallet
 		type WeightInfo: WeightInfo;
+		/// The currency in which spending budgets of roles are denominated and proposal deposits
+		/// are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// The amount reserved from an account proposing a call which requires approvals.
+		#[pallet::constant]
+		type ProposalDeposit: Get<BalanceOf<Self>>;
+		/// The number of blocks during which a proposed call can be approved.
+		#[pallet::constant]
+		type ProposalTimeout: Get<BlockNumberFor<Self>>;
+		/// The number of blocks during which an invitation to a role can be accepted.
+		#[pallet::constant]
+		type InvitationTimeout: Get<BlockNumberFor<Self>>;
+		/// Defines the maximum number of approvals a call can require.
+		type MaxApprovals: Get<u32>;
+		/// Defines the maximum length of an encoded proposed call.
+		type MaxProposedCallLength: Get<u32>;
+		/// The enforcement mode in effect until it is changed by the management origin.
+		#[pallet::constant]
+		type DefaultEnforcementMode: Get<EnforcementMode>;
 		/// Describes the metadata of a call, which is associated with roles to define permissions.
-		type CallMetadata: FullCodec + MaxEncodedLen + TypeInfo + Parameter + From<(u64, u8)>;
+		type CallMetadata: FullCodec
+			+ MaxEncodedLen
+			+ TypeInfo
+			+ Parameter
+			+ From<(u64, u8)>
+			+ Into<(u64, u8)>;
 		/// RuntimeCall time extended with GetCallMetadataIndecies trait
 		type ExtendedRuntimeCall: Parameter
 			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
 			+ Debug
 			+ From<Call<Self>>
 			+ GetCallMetadataIndecies
+			+ CallConstraint
+			+ CallSpending<BalanceOf<Self>>
+			+ ResourceExtractor
 			+ UnfilteredDispatchable<RuntimeOrigin = Self::RuntimeOrigin>
 			+ GetDispatchInfo;
 	}
@@ -93,29 +225,347 @@ pub mod pallet {
 	pub type Roles<T: Config> =
 		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleInfoOf<T>, OptionQuery>;
 
+	/// Holds the human-readable description of a role
+	#[pallet::storage]
+	#[pallet::getter(fn role_metadata)]
+	pub type RolesMetadata<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleMetadataOf<T>, OptionQuery>;
+
 	/// Holds account's roles
 	#[pallet::storage]
 	#[pallet::getter(fn account_roles)]
 	pub type AccountRoles<T: Config> =
 		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, AccountRolesListOf<T>, OptionQuery>;
 
+	/// Holds group's members
+	#[pallet::storage]
+	#[pallet::getter(fn group_members)]
+	pub type GroupMembers<T: Config> =
+		StorageMap<_, Blake2_128Concat, GroupNameOf<T>, GroupMembersListOf<T>, OptionQuery>;
+
+	/// Holds the groups an account is a member of
+	#[pallet::storage]
+	#[pallet::getter(fn account_groups)]
+	pub type AccountGroups<T: Config> =
+		StorageMap<_, Blake2_128Concat, AccountIdOf<T>, AccountGroupsListOf<T>, OptionQuery>;
+
+	/// Holds roles assigned to a group, which are held by every member of the group
+	#[pallet::storage]
+	#[pallet::getter(fn group_roles)]
+	pub type GroupRoles<T: Config> =
+		StorageMap<_, Blake2_128Concat, GroupNameOf<T>, GroupRolesListOf<T>, OptionQuery>;
+
+	/// Holds the accounts each role is assigned to, indexing `AccountRoles` by role
+	#[pallet::storage]
+	pub type RoleHolders<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	/// Holds the groups each role is assigned to, indexing `GroupRoles` by role
+	#[pallet::storage]
+	pub type RoleGroups<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		Blake2_128Concat,
+		GroupNameOf<T>,
+		(),
+		OptionQuery,
+	>;
+
+	/// Holds roles held by every account satisfying their membership rule, along with the rule
+	#[pallet::storage]
+	#[pallet::getter(fn role_membership_rules)]
+	pub type RoleMembershipRules<T: Config> = StorageValue<_, RoleMembershipRulesOf<T>, ValueQuery>;
+
 	/// Holds call's associated roles
 	#[pallet::storage]
 	#[pallet::getter(fn call_roles)]
 	pub type CallRoles<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::CallMetadata, CallRolesListOf<T>, OptionQuery>;
 
+	/// Holds constraints on call's arguments attached to role's permissions to execute the call,
+	/// all of which must be satisfied
+	#[pallet::storage]
+	#[pallet::getter(fn call_constraints)]
+	pub type CallConstraints<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CallMetadata,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		CallConstraintsListOf<T>,
+		OptionQuery,
+	>;
+
+	/// Holds roles denied to execute a call, overriding any permission granted to their holders
+	#[pallet::storage]
+	#[pallet::getter(fn call_denied_roles)]
+	pub type CallDeniedRoles<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CallMetadata, CallRolesListOf<T>, OptionQuery>;
+
+	/// Holds calls denied to an account, overriding any permission granted to the account
+	#[pallet::storage]
+	#[pallet::getter(fn account_denial)]
+	pub type AccountDenials<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		T::CallMetadata,
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::type_value]
+	pub fn DefaultEnforcementModeFor<T: Config>() -> EnforcementMode {
+		T::DefaultEnforcementMode::get()
+	}
+
+	/// Holds the enforcement mode applied to calls without any role attached to them
+	#[pallet::storage]
+	#[pallet::getter(fn enforcement_mode)]
+	pub type ActiveEnforcementMode<T: Config> =
+		StorageValue<_, EnforcementMode, ValueQuery, DefaultEnforcementModeFor<T>>;
+
+	/// Holds calls which can be executed by any account regardless of the enforcement mode
+	#[pallet::storage]
+	pub type PublicCalls<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::CallMetadata, (), OptionQuery>;
+
+	/// Holds roles allowed to execute every call of a pallet, keyed by the pallet index
+	#[pallet::storage]
+	#[pallet::getter(fn pallet_roles)]
+	pub type PalletRoles<T: Config> =
+		StorageMap<_, Blake2_128Concat, u64, CallRolesListOf<T>, OptionQuery>;
+
+	/// Holds role's parent roles, whose permissions are inherited by the role
+	#[pallet::storage]
+	#[pallet::getter(fn role_parents)]
+	pub type RoleParents<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, ParentRolesListOf<T>, OptionQuery>;
+
+	/// Holds the roles inheriting directly from each role, indexing `RoleParents` by parent
+	#[pallet::storage]
+	#[pallet::getter(fn role_children)]
+	pub type RoleChildren<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, ChildRolesListOf<T>, OptionQuery>;
+
+	/// Holds the number of calls dispatched using a globally rate limited role in the current
+	/// period
+	#[pallet::storage]
+	#[pallet::getter(fn role_usage)]
+	pub type RoleUsage<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, UsageWindowOf<T>, OptionQuery>;
+
+	/// Holds the number of calls dispatched by an account using a rate limited role in the current
+	/// period
+	#[pallet::storage]
+	#[pallet::getter(fn account_role_usage)]
+	pub type AccountRoleUsage<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		UsageWindowOf<T>,
+		OptionQuery,
+	>;
+
+	/// Holds roles which cannot be held by the same account as the role
+	#[pallet::storage]
+	#[pallet::getter(fn exclusive_roles)]
+	pub type ExclusiveRoles<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, ExclusiveRolesListOf<T>, OptionQuery>;
+
+	/// Holds the number of approvals from holders of a role needed to dispatch a call attached
+	/// to the role
+	#[pallet::storage]
+	#[pallet::getter(fn approval_threshold)]
+	pub type ApprovalThresholds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CallMetadata,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		u32,
+		OptionQuery,
+	>;
+
+	/// Holds calls waiting for approvals, keyed by the role they are proposed with and the hash
+	/// of the encoded call
+	#[pallet::storage]
+	#[pallet::getter(fn proposal)]
+	pub type Proposals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		Identity,
+		T::Hash,
+		CallProposalOf<T>,
+		OptionQuery,
+	>;
+
+	/// Holds role's spending budget
+	#[pallet::storage]
+	#[pallet::getter(fn role_budget)]
+	pub type RoleBudgets<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, SpendingBudgetOf<T>, OptionQuery>;
+
+	/// Holds the amount of funds moved using a role in the current budget period
+	#[pallet::storage]
+	#[pallet::getter(fn role_spending)]
+	pub type RoleSpending<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, SpendingWindowOf<T>, OptionQuery>;
+
+	/// Holds the admin role of a role, whose holders are allowed to manage the role
+	#[pallet::storage]
+	#[pallet::getter(fn role_admin)]
+	pub type RoleAdmins<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleNameOf<T>, OptionQuery>;
+
+	/// Holds the validity period of account's time-bound role assignments
+	#[pallet::storage]
+	#[pallet::getter(fn assignment_validity)]
+	pub type AssignmentValidities<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		AssignmentValidityOf<T>,
+		OptionQuery,
+	>;
+
+	/// Holds the resources account's role assignments are scoped to
+	#[pallet::storage]
+	#[pallet::getter(fn assignment_scopes)]
+	pub type AssignmentScopes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		AssignmentScopesListOf<T>,
+		OptionQuery,
+	>;
+
+	/// Holds pending invitations of accounts to roles, along with the block at which they expire
+	#[pallet::storage]
+	#[pallet::getter(fn invitation)]
+	pub type Invitations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AccountIdOf<T>,
+		Blake2_128Concat,
+		RoleNameOf<T>,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Holds role assignments scheduled to expire at a particular block
+	#[pallet::storage]
+	#[pallet::getter(fn expiring_assignments)]
+	pub type ExpiringAssignments<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, ExpiringAssignmentsOf<T>, ValueQuery>;
+
+	/// Holds the last block whose expiring role assignments were cleaned up
+	#[pallet::storage]
+	#[pallet::getter(fn last_expiry_cleanup)]
+	pub type LastExpiryCleanup<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
+
+	/// Holds the emergency pause of role-based dispatch, if any
+	#[pallet::storage]
+	#[pallet::getter(fn pause_state)]
+	pub type DispatchPause<T: Config> = StorageValue<_, PauseStateOf<T>, OptionQuery>;
+
+	/// Holds the progress of forced removals of roles spanning several calls
+	#[pallet::storage]
+	#[pallet::getter(fn role_removal)]
+	pub type RoleRemovals<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleRewriteCursorOf, OptionQuery>;
+
+	/// Holds the progress of renamings of roles spanning several calls
+	#[pallet::storage]
+	#[pallet::getter(fn role_rename)]
+	pub type RoleRenames<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleRenameOf<T>, OptionQuery>;
+
+	/// Holds the names of roles being renamed, by their new name
+	#[pallet::storage]
+	#[pallet::getter(fn role_rename_source)]
+	pub type RoleRenameSources<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleNameOf<T>, OptionQuery>;
+
+	/// Holds the progress of clonings of roles spanning several calls, by the name of the clone
+	#[pallet::storage]
+	#[pallet::getter(fn role_clone)]
+	pub type RoleClones<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleCloneOf<T>, OptionQuery>;
+
+	/// Holds the names of clones being built, by the name of the role being cloned
+	#[pallet::storage]
+	#[pallet::getter(fn role_clone_target)]
+	pub type RoleCloneTargets<T: Config> =
+		StorageMap<_, Blake2_128Concat, RoleNameOf<T>, RoleNameOf<T>, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// A new role was created.
 		RoleCreated { role_name: RoleNameOf<T> },
+		/// The settings of a role were updated.
+		RoleUpdated {
+			role_name: RoleNameOf<T>,
+			old_settings: RoleSettingsOf<T>,
+			new_settings: RoleSettingsOf<T>,
+		},
+		/// A batch of policy changes was applied as a whole.
+		PolicyChangesApplied { count: u32 },
+		/// The human-readable description of a role was set or cleared.
+		RoleMetadataSet { role_name: RoleNameOf<T>, metadata: Option<RoleMetadataOf<T>> },
+		/// A role was suspended.
+		RoleSuspended { role_name: RoleNameOf<T> },
+		/// A suspended role was resumed.
+		RoleResumed { role_name: RoleNameOf<T> },
 		/// A role was removed.
 		RoleRemoved { role_name: RoleNameOf<T> },
 		/// An account was assigned a role.
 		AccountAssignedToRole { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// An account was invited to a role.
+		RoleInvitationSent {
+			role_name: RoleNameOf<T>,
+			who: AccountIdOf<T>,
+			expires_at: BlockNumberFor<T>,
+		},
+		/// An account accepted an invitation to a role and was assigned the role.
+		RoleInvitationAccepted { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// An account declined an invitation to a role.
+		RoleInvitationDeclined { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// An invitation of an account to a role was cancelled.
+		RoleInvitationCancelled { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// An invitation of an account to a role expired without being accepted and was removed.
+		RoleInvitationExpired { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
 		/// A role was unassigned from an account.
 		AccountUnassignedFromRole { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// A new group was created.
+		GroupCreated { group_name: GroupNameOf<T> },
+		/// A group was removed.
+		GroupRemoved { group_name: GroupNameOf<T> },
+		/// An account became a member of a group.
+		AccountAddedToGroup { group_name: GroupNameOf<T>, who: AccountIdOf<T> },
+		/// An account stopped being a member of a group.
+		AccountRemovedFromGroup { group_name: GroupNameOf<T>, who: AccountIdOf<T> },
+		/// A group was assigned a role.
+		GroupAssignedToRole { role_name: RoleNameOf<T>, group_name: GroupNameOf<T> },
+		/// A role was unassigned from a group.
+		GroupUnassignedFromRole { role_name: RoleNameOf<T>, group_name: GroupNameOf<T> },
 		/// A call was added to a role's permission.
 		CallAddedToRole { role_name: RoleNameOf<T>, call_metadata: T::CallMetadata },
 		/// A call was removed from a role's permissions.
@@ -126,6 +576,115 @@ pub mod pallet {
 			who: AccountIdOf<T>,
 			call_metadata: T::CallMetadata,
 		},
+		/// A role started inheriting permissions of a parent role.
+		ParentRoleAdded { role_name: RoleNameOf<T>, parent_role_name: RoleNameOf<T> },
+		/// A role stopped inheriting permissions of a parent role.
+		ParentRoleRemoved { role_name: RoleNameOf<T>, parent_role_name: RoleNameOf<T> },
+		/// An account's role assignment was limited to a period of blocks.
+		AssignmentValiditySet {
+			role_name: RoleNameOf<T>,
+			who: AccountIdOf<T>,
+			validity: AssignmentValidityOf<T>,
+		},
+		/// An account's role assignment was scoped to a resource.
+		AssignmentScopeSet { role_name: RoleNameOf<T>, who: AccountIdOf<T>, scope: ResourceOf<T> },
+		/// An account's time-bound role assignment expired and was removed.
+		AssignmentExpired { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// The admin role of a role was set or cleared.
+		RoleAdminSet { role_name: RoleNameOf<T>, admin_role_name: Option<RoleNameOf<T>> },
+		/// Every call of a pallet was added to a role's permissions.
+		PalletWildcardAddedToRole { role_name: RoleNameOf<T>, pallet_index: u64 },
+		/// Every call of a pallet was removed from a role's permissions.
+		PalletWildcardRemovedFromRole { role_name: RoleNameOf<T>, pallet_index: u64 },
+		/// The rate limit of a role was set or cleared.
+		RoleRateLimitSet { role_name: RoleNameOf<T>, rate_limit: Option<RateLimit> },
+		/// The number of approvals needed to dispatch a call with a role was set or cleared.
+		ApprovalThresholdSet {
+			role_name: RoleNameOf<T>,
+			call_metadata: T::CallMetadata,
+			threshold: Option<u32>,
+		},
+		/// A call requiring approvals was proposed by a holder of a role.
+		CallProposed {
+			role_name: RoleNameOf<T>,
+			who: AccountIdOf<T>,
+			call_hash: T::Hash,
+			call_metadata: T::CallMetadata,
+		},
+		/// A proposed call was approved by a holder of the role.
+		CallApproved {
+			role_name: RoleNameOf<T>,
+			call_hash: T::Hash,
+			who: AccountIdOf<T>,
+			approvals: u32,
+		},
+		/// A proposed call reached its approval threshold and was dispatched.
+		ProposedCallDispatched {
+			call_hash: T::Hash,
+			role_name: RoleNameOf<T>,
+			who: AccountIdOf<T>,
+			result: DispatchResult,
+		},
+		/// A proposed call was cancelled by its proposer.
+		ProposalCancelled { role_name: RoleNameOf<T>, call_hash: T::Hash },
+		/// A proposed call was dropped because it was not approved in time.
+		ProposalTimedOut { role_name: RoleNameOf<T>, call_hash: T::Hash },
+		/// A proposed call was dropped because the role it was proposed with was removed or
+		/// renamed to a name the call was already proposed with.
+		ProposalDropped { role_name: RoleNameOf<T>, call_hash: T::Hash },
+		/// The account a role dispatches calls as accepted being impersonated by the role.
+		ImpersonationAccepted { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// The account a role dispatches calls as revoked its consent to be impersonated.
+		ImpersonationRevoked { role_name: RoleNameOf<T>, who: AccountIdOf<T> },
+		/// Two roles were made mutually exclusive.
+		ExclusiveRolesAdded { role_name: RoleNameOf<T>, other_role_name: RoleNameOf<T> },
+		/// Two roles are no longer mutually exclusive.
+		ExclusiveRolesRemoved { role_name: RoleNameOf<T>, other_role_name: RoleNameOf<T> },
+		/// The enforcement mode was changed.
+		EnforcementModeSet { mode: EnforcementMode },
+		/// A call was made public.
+		PublicCallAdded { call_metadata: T::CallMetadata },
+		/// A call is no longer public.
+		PublicCallRemoved { call_metadata: T::CallMetadata },
+		/// A call was denied to a role.
+		CallDeniedToRole { role_name: RoleNameOf<T>, call_metadata: T::CallMetadata },
+		/// A call is no longer denied to a role.
+		CallDenialRemovedFromRole { role_name: RoleNameOf<T>, call_metadata: T::CallMetadata },
+		/// A call was denied to an account.
+		CallDeniedToAccount { who: AccountIdOf<T>, call_metadata: T::CallMetadata },
+		/// A call is no longer denied to an account.
+		CallDenialRemovedFromAccount { who: AccountIdOf<T>, call_metadata: T::CallMetadata },
+		/// The membership rule of a role was set or cleared.
+		RoleMembershipRuleSet { role_name: RoleNameOf<T>, rule: Option<MembershipRuleOf<T>> },
+		/// The spending budget of a role was set or cleared.
+		RoleBudgetSet { role_name: RoleNameOf<T>, budget: Option<SpendingBudgetOf<T>> },
+		/// An approved call was not dispatched with a role, as the budget left to the role for the
+		/// current period does not cover the funds moved by the call.
+		RoleBudgetExhausted { role_name: RoleNameOf<T>, window_start: BlockNumberFor<T> },
+		/// Constraints on call's arguments were attached to or detached from a role's permission.
+		CallConstraintsSet {
+			role_name: RoleNameOf<T>,
+			call_metadata: T::CallMetadata,
+			constraints: CallConstraintsListOf<T>,
+		},
+		/// Role-based dispatch was paused.
+		RoleDispatchPaused { block_extension: bool, until: Option<BlockNumberFor<T>> },
+		/// Role-based dispatch was unpaused.
+		RoleDispatchUnpaused,
+		/// A forced removal of a role was started. The role is suspended until it is removed.
+		RoleRemovalStarted { role_name: RoleNameOf<T> },
+		/// A renaming of a role was started. Both names grant the role's permissions until the
+		/// role is renamed.
+		RoleRenameStarted { role_name: RoleNameOf<T>, new_role_name: RoleNameOf<T> },
+		/// A role was renamed.
+		RoleRenamed { role_name: RoleNameOf<T>, new_role_name: RoleNameOf<T> },
+		/// A renaming of a role was cancelled. The references moved so far are moved back to the
+		/// role's name until the role is renamed back.
+		RoleRenameCancelled { role_name: RoleNameOf<T>, new_role_name: RoleNameOf<T> },
+		/// A cloning of a role was started. The clone grants the permissions copied so far.
+		RoleCloneStarted { role_name: RoleNameOf<T>, new_role_name: RoleNameOf<T> },
+		/// A role was cloned.
+		RoleCloned { role_name: RoleNameOf<T>, new_role_name: RoleNameOf<T> },
 	}
 
 	#[pallet::error]
@@ -137,6 +696,12 @@ pub mod pallet {
 		RoleExists,
 		/// The role cannot be found, it might have been removed or it does not exist.
 		RoleDoesNotExist,
+		/// The role is suspended and grants no permissions until it is resumed.
+		RoleSuspended,
+		/// The role cannot be suspended because it is already suspended.
+		RoleAlreadySuspended,
+		/// The role cannot be resumed because it is not suspended.
+		RoleNotSuspended,
 		/// The call cannot be added to the role because it is already part of the role's
 		/// permissions.
 		CallAlreadyAttachedToRole,
@@ -151,8 +716,188 @@ pub mod pallet {
 		CallNotAttachedToRole,
 		/// The role cannot be assigned because it is already assigned to the account.
 		RoleAlreadyAssigned,
+		/// The account cannot be invited because it already has a pending invitation to the role.
+		InvitationAlreadyExists,
+		/// The invitation cannot be found, it might have been accepted, declined or cancelled.
+		InvitationNotFound,
+		/// The invitation can no longer be accepted because its acceptance period has ended.
+		InvitationExpired,
 		/// The operation cannot be completed because a role needed for it was not found.
 		MissingRole,
+		/// The parent role cannot be added because the role already inherits from it.
+		ParentRoleAlreadyAdded,
+		/// The parent role cannot be removed because the role does not inherit from it.
+		ParentRoleNotAdded,
+		/// The operation cannot be completed because adding this parent role would exceed the
+		/// allowed number of parent roles per role.
+		TooManyParentRoles,
+		/// The parent role cannot be added because the role would end up inheriting from itself.
+		RoleHierarchyCycle,
+		/// The parent role cannot be added because the resulting hierarchy would exceed the
+		/// allowed depth.
+		RoleHierarchyTooDeep,
+		/// The assignment validity period is empty or has already ended.
+		InvalidAssignmentValidity,
+		/// The operation cannot be completed because it would exceed the allowed number of
+		/// role assignments expiring at the same block.
+		TooManyExpiriesPerBlock,
+		/// The role is assigned to the account, but the assignment is not in effect at the
+		/// current block.
+		AssignmentNotActive,
+		/// The role assignment is scoped to resources other than the one the call operates on, or
+		/// is scoped while the operation is not limited to any resource.
+		ResourceOutOfScope,
+		/// The pallet wildcard cannot be added to the role because it is already part of the
+		/// role's permissions.
+		PalletWildcardAlreadyAttachedToRole,
+		/// The pallet wildcard cannot be removed from the role because it is not part of the
+		/// role's permissions.
+		PalletWildcardNotAttachedToRole,
+		/// The call is attached to the role, but its arguments violate a constraint attached to
+		/// the role's permission.
+		CallConstraintViolated,
+		/// The rate limit must allow at least one call within a non-empty period.
+		InvalidRateLimit,
+		/// The role has reached its rate limit for the current period.
+		RateLimitExceeded,
+		/// The operation cannot be completed because it would exceed the allowed number of roles
+		/// with a membership rule.
+		TooManyRuleBasedRoles,
+		/// The spending budget must have a non-empty period.
+		InvalidBudget,
+		/// The call would move more funds than left in the role's budget for the current period.
+		BudgetExceeded,
+		/// The call cannot be denied because it is already denied to the role or account.
+		CallAlreadyDenied,
+		/// The denial cannot be removed because the call is not denied to the role or account.
+		CallNotDenied,
+		/// The call is denied to the account or to one of the roles it holds.
+		CallDenied,
+		/// The approval threshold must require at least two and at most `MaxApprovals` approvals.
+		InvalidApprovalThreshold,
+		/// The call cannot be dispatched directly because it requires approvals from several
+		/// holders of the role.
+		ApprovalRequired,
+		/// The call cannot be proposed because it does not require approvals.
+		ApprovalNotRequired,
+		/// The call cannot be proposed because the same call is already waiting for approvals.
+		ProposalAlreadyExists,
+		/// The proposal cannot be found, it might have been dispatched or dropped.
+		ProposalNotFound,
+		/// The proposal can no longer be approved because its approval period has ended.
+		ProposalExpired,
+		/// The proposal cannot be dropped because it can still be approved.
+		ProposalNotExpired,
+		/// The account has already approved the proposal.
+		AlreadyApproved,
+		/// The operation cannot be completed because it would exceed the allowed number of
+		/// approvals.
+		TooManyApprovals,
+		/// Only the account which proposed the call can cancel the proposal.
+		NotProposer,
+		/// The call cannot be proposed because its encoding exceeds the allowed length.
+		ProposedCallTooLong,
+		/// The proposed call cannot be decoded, e.g. because of a runtime upgrade.
+		UndecodableProposedCall,
+		/// The weight limit given for dispatching the proposed call is too low.
+		MaxWeightTooLow,
+		/// Only the account the role dispatches calls as can accept or revoke the impersonation.
+		NotImpersonatedAccount,
+		/// The impersonation has already been accepted.
+		ConsentAlreadyGiven,
+		/// The impersonation has already been revoked.
+		ConsentAlreadyRevoked,
+		/// The role dispatches calls as an account which has not accepted the impersonation or
+		/// has revoked it.
+		ImpersonationNotConsented,
+		/// The roles cannot be made mutually exclusive because they already are.
+		RolesAlreadyExclusive,
+		/// The roles cannot stop being mutually exclusive because they are not.
+		RolesNotExclusive,
+		/// A role cannot be mutually exclusive with itself.
+		RoleExclusiveWithItself,
+		/// The operation cannot be completed because it would exceed the allowed number of
+		/// roles a single role can be mutually exclusive with.
+		TooManyExclusiveRoles,
+		/// The role cannot be assigned because the account holds a role mutually exclusive with
+		/// it.
+		ExclusiveRoleHeld,
+		/// The group cannot be created because a group with the same name already exists.
+		GroupExists,
+		/// The group cannot be found, it might have been removed or it does not exist.
+		GroupDoesNotExist,
+		/// The group cannot be removed because it still has members or roles assigned to it.
+		GroupNotEmpty,
+		/// The account cannot be added to the group because it is already a member.
+		AlreadyGroupMember,
+		/// The account cannot be removed from the group because it is not a member.
+		NotGroupMember,
+		/// The operation cannot be completed because adding this account would exceed the
+		/// allowed number of members per group.
+		TooManyGroupMembers,
+		/// The operation cannot be completed because adding this group would exceed the allowed
+		/// number of groups per account.
+		TooManyGroupsPerAccount,
+		/// The operation cannot be completed because adding this role would exceed the allowed
+		/// number of roles per group.
+		TooManyRolesPerGroup,
+		/// The role cannot be assigned because it is already assigned to the group.
+		RoleAlreadyAssignedToGroup,
+		/// The role cannot be unassigned because it is not assigned to the group.
+		RoleNotAssignedToGroup,
+		/// The call cannot be made public because it is already public.
+		CallAlreadyPublic,
+		/// The call cannot be removed from public calls because it is not public.
+		CallNotPublic,
+		/// Role-based dispatch is paused.
+		DispatchPaused,
+		/// Role-based dispatch cannot be unpaused because it is not paused.
+		DispatchNotPaused,
+		/// The pause cannot end at or before the current block.
+		InvalidPauseEnd,
+		/// The role cannot be resumed, renamed or referenced anew because it is being removed.
+		RoleBeingRemoved,
+		/// The role cannot be removed, updated, suspended, resumed, cloned or renamed to another
+		/// name, nor can its rate limit, membership rule or impersonation consent change, because
+		/// it is being renamed or is the new name of a role being renamed.
+		RoleBeingRenamed,
+		/// The progress of the forced removal cannot be saved because the storage key is too long.
+		StorageKeyTooLong,
+		/// The role cannot be removed, renamed or cloned, or cloned from another role or with
+		/// other members, because it is being cloned or a clone of it is being built.
+		RoleBeingCloned,
+		/// The role cannot be managed by holders of its admin role because it dispatches calls as
+		/// root or as another account, or bypasses the call filter.
+		PrivilegedRole,
+		/// The resource cannot be added to the role assignment's scopes because it is already
+		/// part of them.
+		ScopeAlreadyAssigned,
+		/// The role assignment cannot be scoped to more resources.
+		TooManyScopes,
+		/// A role cannot both be held through a membership rule and be mutually exclusive with
+		/// another role, directly or through inheritance, as accounts satisfying the rule cannot
+		/// be checked for exclusive roles.
+		RuleBasedExclusiveRole,
+		/// The invitation cannot be removed because it has not expired yet.
+		InvitationNotExpired,
+		/// The renaming cannot be cancelled because the role is not being renamed.
+		RoleNotBeingRenamed,
+		/// The role cannot inherit from a role with exclusive roles because too many accounts
+		/// and groups hold it or its descendants to check them all.
+		TooManyRoleHolders,
+		/// The operation cannot be completed because adding this parent role would exceed the
+		/// allowed number of roles inheriting directly from the parent role.
+		TooManyChildRoles,
+		/// The operation cannot be completed because the role has more descendants than can be
+		/// resolved, i.e. more than `MaxRoleHierarchyDepth` times `ChildRolesLimit`.
+		TooManyDescendantRoles,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::remove_expired_assignments(now, remaining_weight)
+		}
 	}
 
 	#[pallet::genesis_config]
@@ -163,11 +908,24 @@ pub mod pallet {
 		pub calls: Vec<(RoleNameOf<T>, ModuleCallIndex)>,
 		/// [role_name, assgined_account]
 		pub users: Vec<(RoleNameOf<T>, AccountIdOf<T>)>,
+		/// [role_name, parent_role_name]
+		pub parents: Vec<(RoleNameOf<T>, RoleNameOf<T>)>,
+		/// [call_metadata]
+		pub public_calls: Vec<ModuleCallIndex>,
+		/// [role_name, other_role_name]
+		pub exclusions: Vec<(RoleNameOf<T>, RoleNameOf<T>)>,
 	}
 
 	impl<T: Config> Default for GenesisConfig<T> {
 		fn default() -> Self {
-			Self { roles: Default::default(), calls: Default::default(), users: Default::default() }
+			Self {
+				roles: Default::default(),
+				calls: Default::default(),
+				users: Default::default(),
+				parents: Default::default(),
+				public_calls: Default::default(),
+				exclusions: Default::default(),
+			}
 		}
 	}
 
@@ -198,16 +956,47 @@ pub mod pallet {
 						.expect("Epected to insert a role name to call's roles set");
 				});
 			});
+			self.parents.iter().cloned().for_each(|(name, parent_name)| {
+				Pallet::<T>::ensure_can_inherit(&name, &parent_name)
+					.expect("Expected a valid role hierarchy during genesis build");
+				RoleParents::<T>::mutate(&name, |parents| {
+					let parents = parents.get_or_insert(ParentRolesListOf::<T>::default());
+					parents
+						.try_insert(parent_name.clone())
+						.expect("Epected to insert a role name to role's parents set");
+				});
+				Pallet::<T>::insert_child_role(&parent_name, &name)
+					.expect("Expected to insert a role name to role's children set");
+				Pallet::<T>::inc_role_consumers(&name)
+					.expect("Expected to increase consumers counter during genesis build");
+				Pallet::<T>::inc_role_consumers(&parent_name)
+					.expect("Expected to increase consumers counter during genesis build");
+			});
+			self.exclusions.iter().cloned().for_each(|(name, other_name)| {
+				Pallet::<T>::insert_exclusive_roles(&name, &other_name)
+					.expect("Expected valid exclusive roles during genesis build");
+				Pallet::<T>::inc_role_consumers(&name)
+					.expect("Expected to increase consumers counter during genesis build");
+				Pallet::<T>::inc_role_consumers(&other_name)
+					.expect("Expected to increase consumers counter during genesis build");
+			});
 			self.users.iter().cloned().for_each(|(name, user)| {
-				AccountRoles::<T>::mutate(user, |account_roles| {
+				Pallet::<T>::ensure_no_exclusive_roles(&user, &name)
+					.expect("Expected no exclusive roles held by an account during genesis build");
+				AccountRoles::<T>::mutate(&user, |account_roles| {
 					let account_roles =
 						account_roles.get_or_insert(AccountRolesListOf::<T>::default());
 					Pallet::<T>::inc_role_consumers(&name)
 						.expect("Expected to increase consumers counter during genesis build");
 					account_roles
-						.try_insert(name)
+						.try_insert(name.clone())
 						.expect("Epected to insert a role name to account's roles set");
 				});
+				RoleHolders::<T>::insert(name, user, ());
+			});
+			self.public_calls.iter().cloned().for_each(|call_metadata| {
+				let call_metadata: T::CallMetadata = call_metadata.into();
+				PublicCalls::<T>::insert(call_metadata, ());
 			});
 		}
 	}
@@ -246,7 +1035,8 @@ pub mod pallet {
 
 		/// Adds a call to a role's allowed calls list.
 		/// This means that accounts with the role can execute the call.
-		/// Only callable by accounts with the appropriate management origin.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
 		///
 		/// # Parameters
 		/// - `role_name`: The name of the role to modify.
@@ -258,7 +1048,7 @@ pub mod pallet {
 			role_name: RoleNameOf<T>,
 			call: Box<T::ExtendedRuntimeCall>,
 		) -> DispatchResultWithPostInfo {
-			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_role_manager(origin, &role_name)?;
 			Self::check_role_existance_and_version(&role_name)?;
 
 			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
@@ -277,7 +1067,8 @@ pub mod pallet {
 
 		/// Removes a call from a role's allowed calls list.
 		/// This means that accounts with the role will no longer be able to execute the call.
-		/// Only callable by accounts with the appropriate management origin.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
 		///
 		/// # Parameters
 		/// - `role_name`: The name of the role to modify.
@@ -289,7 +1080,7 @@ pub mod pallet {
 			role_name: RoleNameOf<T>,
 			call: Box<T::ExtendedRuntimeCall>,
 		) -> DispatchResultWithPostInfo {
-			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_role_manager(origin, &role_name)?;
 			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
 
 			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
@@ -302,6 +1093,8 @@ pub mod pallet {
 					Err(Error::<T>::CallNotAttachedToRole)
 				}
 			})?;
+			CallConstraints::<T>::remove(&call_metadata, &role_name);
+			ApprovalThresholds::<T>::remove(&call_metadata, &role_name);
 			Self::dec_role_consumers(&role_name)?;
 			Self::deposit_event(Event::<T>::CallRemovedFromRole { role_name, call_metadata });
 
@@ -309,7 +1102,8 @@ pub mod pallet {
 		}
 
 		/// Assigns a role to an account, granting it the permissions defined for the role.
-		/// Only callable by accounts with the appropriate management origin.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
 		///
 		/// # Parameters
 		/// - `who`: The account to assign the role to.
@@ -321,24 +1115,15 @@ pub mod pallet {
 			who: AccountIdOf<T>,
 			role_name: RoleNameOf<T>,
 		) -> DispatchResultWithPostInfo {
-			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
-			Self::check_role_existance_and_version(&role_name)?;
-
-			AccountRoles::<T>::mutate(&who, |account_roles| {
-				let account_roles = account_roles.get_or_insert(AccountRolesListOf::<T>::default());
-				ensure!(!account_roles.contains(&role_name), Error::<T>::RoleAlreadyAssigned);
-				account_roles
-					.try_insert(role_name.clone())
-					.map_err(|_| Error::<T>::TooManyRolesPerAccount)
-			})?;
-			Self::inc_role_consumers(&role_name)?;
-			Self::deposit_event(Event::<T>::AccountAssignedToRole { role_name, who });
+			Self::ensure_role_manager(origin, &role_name)?;
+			Self::do_assign_role(&who, &role_name)?;
 
 			Ok(().into())
 		}
 
 		/// Unassigns a role from an account, revoking its permissions defined for the role.
-		/// Only callable by accounts with the appropriate management origin.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
 		///
 		/// # Parameters
 		/// - `who`: The account to unassign the role from.
@@ -350,26 +1135,17 @@ pub mod pallet {
 			who: AccountIdOf<T>,
 			role_name: RoleNameOf<T>,
 		) -> DispatchResultWithPostInfo {
-			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_role_manager(origin, &role_name)?;
 			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
-
-			AccountRoles::<T>::mutate(&who, |account_roles| {
-				if let Some(account_roles) = account_roles.as_mut() {
-					ensure!(account_roles.contains(&role_name), Error::<T>::MissingRole);
-					account_roles.remove(&role_name);
-					Ok(())
-				} else {
-					Err(Error::<T>::MissingRole)
-				}
-			})?;
-			Self::dec_role_consumers(&role_name)?;
-			Self::deposit_event(Event::<T>::AccountUnassignedFromRole { role_name, who });
+			Self::do_unassign_role(&who, &role_name)?;
 
 			Ok(().into())
 		}
 
-		/// Removes a role definition entirely, unassigning it from all accounts and removing all
-		/// calls associated with it. This is a sensitive operation and should be used with caution.
+		/// Removes a role definition entirely. Fails unless the role is no longer referenced, i.e.
+		/// it is not assigned to any account or group, no call, denial or other role refers to it
+		/// and no rate limit usage is counted for it; use `force_remove_role` to remove a role
+		/// still in use.
 		/// Only callable by accounts with the appropriate management origin.
 		///
 		/// # Parameters
@@ -381,10 +1157,15 @@ pub mod pallet {
 			role_name: RoleNameOf<T>,
 		) -> DispatchResultWithPostInfo {
 			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			ensure!(!RoleCloneTargets::<T>::contains_key(&role_name), Error::<T>::RoleBeingCloned);
 
 			Self::roles(&role_name).ok_or(Error::<T>::RoleDoesNotExist)?.check_if_unused()?;
-			Roles::<T>::remove(&role_name);
-			Self::deposit_event(Event::<T>::RoleRemoved { role_name });
+			ensure!(
+				AccountRoleUsage::<T>::iter_key_prefix(&role_name).next().is_none(),
+				DispatchError::ConsumerRemaining
+			);
+			Self::do_remove_role(role_name);
 
 			Ok(().into())
 		}
@@ -394,6 +1175,8 @@ pub mod pallet {
 		/// This extrinsic allows the caller to execute a runtime call with a given role.
 		/// The role is used to infer the appropriate origin for the call dispatch and to
 		/// handle other role-specific logic.
+		/// A call moving more funds than left in the role's spending budget is not dispatched and
+		/// the extrinsic fails with `BudgetExceeded`.
 		///
 		/// # Parameters
 		/// - `origin`: The origin of the call, expected to be a signed origin.
@@ -413,23 +1196,15 @@ pub mod pallet {
 			call: Box<T::ExtendedRuntimeCall>,
 			with_role: RoleNameOf<T>,
 		) -> DispatchResultWithPostInfo {
-			let who = ensure_signed(origin.clone())?;
-			let role_info = Self::check_role_existance_and_version(&with_role)?;
-			ensure!(
-				Self::account_roles(&who).unwrap_or_default().contains(&with_role),
-				Error::<T>::MissingRole,
-			);
+			let who = ensure_signed(origin)?;
 			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			let role_info =
+				Self::ensure_can_dispatch_with_role(&who, &with_role, &call_metadata, &call)?;
 			ensure!(
-				Self::call_roles(&call_metadata).unwrap_or_default().contains(&with_role),
-				Error::<T>::CallNotAttachedToRole,
+				Self::required_approvals(&with_role, &call_metadata).is_none(),
+				Error::<T>::ApprovalRequired
 			);
-			let origin_for_dispatch = role_info.infer_origin(who.clone());
-			let dispatch_result = if role_info.allow_filter_bypassing {
-				call.dispatch_bypass_filter(origin_for_dispatch.into())
-			} else {
-				call.dispatch(origin_for_dispatch.into())
-			};
+			let dispatch_result = Self::dispatch_as_role(&role_info, &with_role, &who, *call)?;
 			Self::deposit_event(Event::<T>::CallDispatchedWithRole {
 				role_name: with_role,
 				who,
@@ -437,78 +1212,3583 @@ pub mod pallet {
 			});
 			dispatch_result
 		}
-	}
-}
 
-impl<T: Config> Pallet<T> {
-	/// Internal utility function to increase the consumer counter for a role.
-	///
-	/// # Parameters
-	/// - `role_name`: The name of the role to increment the consumer counter for.
-	pub fn inc_role_consumers(role_name: &RoleNameOf<T>) -> DispatchResult {
-		Roles::<T>::mutate(role_name, |info| {
-			if let Some(info) = info.as_mut() {
-				info.inc_consumers()
-			} else {
-				Err(Error::<T>::RoleDoesNotExist.into())
+		/// Makes a role inherit all the permissions of a parent role.
+		/// Accounts with the role will be able to execute every call attached to the parent role
+		/// and to any of its ancestors. Fails if that would make an account holding the role or
+		/// one of its descendants hold mutually exclusive roles, or if more than
+		/// `InheritanceChecksLimit` accounts and groups would have to be checked for that.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `parent_role_name`: The name of the role to inherit permissions from.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::add_parent_role(T::InheritanceChecksLimit::get()))]
+		pub fn add_parent_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			parent_role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::do_add_parent_role(&role_name, &parent_role_name)?;
+
+			Ok(().into())
+		}
+
+		/// Makes a role stop inheriting the permissions of a parent role.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `parent_role_name`: The name of the role to stop inheriting permissions from.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::remove_parent_role())]
+		pub fn remove_parent_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			parent_role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			RoleParents::<T>::mutate_exists(&role_name, |parents| {
+				let parents_set = parents.as_mut().ok_or(Error::<T>::ParentRoleNotAdded)?;
+				ensure!(parents_set.remove(&parent_role_name), Error::<T>::ParentRoleNotAdded);
+				if parents_set.is_empty() {
+					*parents = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::remove_child_role(&parent_role_name, &role_name);
+			Self::dec_role_consumers(&role_name)?;
+			Self::dec_role_consumers(&parent_role_name)?;
+			Self::deposit_event(Event::<T>::ParentRoleRemoved { role_name, parent_role_name });
+
+			Ok(().into())
+		}
+
+		/// Assigns a role to an account for a limited period of blocks.
+		/// The assignment grants no permissions before `not_before` and is removed automatically
+		/// once `expires_at` is reached.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `who`: The account to assign the role to.
+		/// - `role_name`: The name of the role to assign.
+		/// - `not_before`: The first block at which the assignment is in effect, if any.
+		/// - `expires_at`: The first block at which the assignment is no longer in effect, if any.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::assign_role_with_validity())]
+		pub fn assign_role_with_validity(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			role_name: RoleNameOf<T>,
+			not_before: Option<BlockNumberFor<T>>,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			let validity = AssignmentValidityOf::<T> { not_before, expires_at };
+			Self::do_assign_role(&who, &role_name)?;
+			Self::set_assignment_validity(&who, &role_name, validity)?;
+
+			Ok(().into())
+		}
+
+		/// Assigns a role to an account scoped to a resource, e.g. an asset or a pool, or adds the
+		/// resource to the scopes of an existing scoped assignment. The assignment only permits
+		/// calls operating on one of its resources, as reported by the runtime's
+		/// `ResourceExtractor` implementation.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `who`: The account to assign the role to.
+		/// - `role_name`: The name of the role to assign.
+		/// - `scope`: The resource the assignment is limited to.
+		#[pallet::call_index(38)]
+		#[pallet::weight(T::WeightInfo::assign_role_with_scope())]
+		pub fn assign_role_with_scope(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			role_name: RoleNameOf<T>,
+			scope: ResourceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			let mut scopes = match Self::assignment_scopes(&who, &role_name) {
+				Some(scopes) => scopes,
+				None => {
+					Self::do_assign_role(&who, &role_name)?;
+					AssignmentScopesListOf::<T>::default()
+				},
+			};
+			ensure!(
+				scopes.try_insert(scope.clone()).map_err(|_| Error::<T>::TooManyScopes)?,
+				Error::<T>::ScopeAlreadyAssigned
+			);
+			AssignmentScopes::<T>::insert(&who, &role_name, scopes);
+			Self::deposit_event(Event::<T>::AssignmentScopeSet { role_name, who, scope });
+
+			Ok(().into())
+		}
+
+		/// Sets or clears the admin role of a role.
+		/// Holders of the admin role are allowed to assign and unassign the role and to edit its
+		/// calls without the management origin.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `admin_role_name`: The name of the role whose holders will manage the role, or `None`
+		///   to leave the role to the management origin only.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::set_role_admin())]
+		pub fn set_role_admin(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			admin_role_name: Option<RoleNameOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+			if let Some(admin_role_name) = &admin_role_name {
+				Self::check_role_existance_and_version(admin_role_name)?;
 			}
-		})
-	}
 
-	/// Internal utility function to decrease the consumer counter for a role.
-	///
-	/// # Parameters
-	/// - `role_name`: The name of the role to decrement the consumer counter for.
-	pub fn dec_role_consumers(role_name: &RoleNameOf<T>) -> DispatchResult {
-		Roles::<T>::mutate(role_name, |info| {
-			if let Some(info) = info.as_mut() {
-				info.dec_consumers()
-			} else {
-				Err(Error::<T>::RoleDoesNotExist.into())
+			if let Some(previous_admin_role_name) = RoleAdmins::<T>::take(&role_name) {
+				Self::dec_role_consumers(&role_name)?;
+				Self::dec_role_consumers(&previous_admin_role_name)?;
 			}
-		})
-	}
+			if let Some(admin_role_name) = &admin_role_name {
+				RoleAdmins::<T>::insert(&role_name, admin_role_name);
+				Self::inc_role_consumers(&role_name)?;
+				Self::inc_role_consumers(admin_role_name)?;
+			}
+			Self::deposit_event(Event::<T>::RoleAdminSet { role_name, admin_role_name });
 
-	/// Checks the existence and version compatibility of a specified role.
-	///
-	/// Given a role name, this function performs two essential checks:
-	///
-	/// 1. It verifies the existence of the role in the storage. If the role does not exist, it
-	///    returns a `RoleDoesNotExist` error.
-	/// 2. It verifies the compatibility of the role's version with the current runtime version. If
-	///    the versions are incompatible, it returns a custom error.
-	///
-	/// # Parameters
-	/// - `role_name`: A reference to the name of the role to check.
-	pub fn check_role_existance_and_version(
-		role_name: &RoleNameOf<T>,
-	) -> Result<RoleInfoOf<T>, DispatchError> {
-		let role_info = Self::roles(role_name).ok_or(Error::<T>::RoleDoesNotExist)?;
-		let current_version = System::<T>::runtime_version();
-		role_info.check_version(current_version)?;
-		Ok(role_info)
-	}
-}
+			Ok(().into())
+		}
 
-impl<T: Config> CallValidator<T::CallMetadata, AccountIdOf<T>> for Pallet<T> {
-	fn validate_by_metadata(
-		call: T::CallMetadata,
-		who: &AccountIdOf<T>,
-	) -> Result<(), TransactionValidityError> {
-		let call_roles = match Self::call_roles(&call) {
-			Some(call_roles) if !call_roles.len().is_zero() => call_roles,
-			_ => return Ok(()),
-		};
-		let account_roles = match Self::account_roles(who) {
-			Some(account_roles) if !account_roles.len().is_zero() => account_roles,
-			_ => return Err(TransactionValidityError::Invalid(InvalidTransaction::Call)),
-		};
-		let role_name = call_roles
-			.intersection(&account_roles)
-			.next()
-			.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Call))?;
-		Self::check_role_existance_and_version(role_name)
-			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Call))?;
-		Ok(())
+		/// Adds every call of a pallet to a role's allowed calls, including calls added to the
+		/// pallet later on.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `pallet_index`: The index of the pallet whose calls are allowed.
+		#[pallet::call_index(11)]
+		#[pallet::weight(T::WeightInfo::add_pallet_wildcard())]
+		pub fn add_pallet_wildcard(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			pallet_index: u64,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			Self::check_role_existance_and_version(&role_name)?;
+
+			PalletRoles::<T>::mutate(pallet_index, |pallet_roles| {
+				let pallet_roles = pallet_roles.get_or_insert(CallRolesListOf::<T>::default());
+				ensure!(
+					!pallet_roles.contains(&role_name),
+					Error::<T>::PalletWildcardAlreadyAttachedToRole
+				);
+				pallet_roles
+					.try_insert(role_name.clone())
+					.map_err(|_| Error::<T>::TooManyRolesPerCall)
+			})?;
+			Self::inc_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::PalletWildcardAddedToRole { role_name, pallet_index });
+
+			Ok(().into())
+		}
+
+		/// Removes a pallet wildcard from a role's allowed calls.
+		/// Calls of the pallet added to the role one by one remain allowed.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `pallet_index`: The index of the pallet whose calls are no longer allowed.
+		#[pallet::call_index(12)]
+		#[pallet::weight(T::WeightInfo::remove_pallet_wildcard())]
+		pub fn remove_pallet_wildcard(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			pallet_index: u64,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			PalletRoles::<T>::mutate(pallet_index, |pallet_roles| {
+				if let Some(pallet_roles) = pallet_roles.as_mut() {
+					ensure!(
+						pallet_roles.remove(&role_name),
+						Error::<T>::PalletWildcardNotAttachedToRole
+					);
+					Ok(())
+				} else {
+					Err(Error::<T>::PalletWildcardNotAttachedToRole)
+				}
+			})?;
+			Self::dec_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::PalletWildcardRemovedFromRole {
+				role_name,
+				pallet_index,
+			});
+
+			Ok(().into())
+		}
+
+		/// Attaches constraints on call's arguments to a role's permission to execute the call,
+		/// replacing the ones attached before. Accounts with the role will only be able to execute
+		/// the call with arguments satisfying all of the constraints.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `call`: The call attached to the role; only its metadata is taken into account.
+		/// - `constraints`: The constraints to attach, or an empty list to allow any arguments
+		///   again.
+		#[pallet::call_index(13)]
+		#[pallet::weight(T::WeightInfo::set_call_constraints())]
+		pub fn set_call_constraints(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+			constraints: CallConstraintsListOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			ensure!(
+				Self::call_roles(&call_metadata).unwrap_or_default().contains(&role_name),
+				Error::<T>::CallNotAttachedToRole
+			);
+			if constraints.is_empty() {
+				CallConstraints::<T>::remove(&call_metadata, &role_name);
+			} else {
+				CallConstraints::<T>::insert(&call_metadata, &role_name, &constraints);
+			}
+			Self::deposit_event(Event::<T>::CallConstraintsSet {
+				role_name,
+				call_metadata,
+				constraints,
+			});
+
+			Ok(().into())
+		}
+
+		/// Sets or clears the number of approvals from distinct holders of a role needed to
+		/// dispatch a call attached to the role. Such calls cannot be dispatched directly; they
+		/// have to be proposed with `propose_call_with_role` and approved with `approve_call`.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `call`: The call attached to the role; only its metadata is taken into account.
+		/// - `threshold`: The number of approvals needed, including the proposer's, or `None` to
+		///   let any holder of the role dispatch the call directly again.
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::set_approval_threshold())]
+		pub fn set_approval_threshold(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+			threshold: Option<u32>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			ensure!(
+				Self::call_roles(&call_metadata).unwrap_or_default().contains(&role_name),
+				Error::<T>::CallNotAttachedToRole
+			);
+			match threshold {
+				Some(threshold) => {
+					ensure!(
+						(2..=T::MaxApprovals::get()).contains(&threshold),
+						Error::<T>::InvalidApprovalThreshold
+					);
+					ApprovalThresholds::<T>::insert(&call_metadata, &role_name, threshold);
+				},
+				None => ApprovalThresholds::<T>::remove(&call_metadata, &role_name),
+			}
+			Self::deposit_event(Event::<T>::ApprovalThresholdSet {
+				role_name,
+				call_metadata,
+				threshold,
+			});
+
+			Ok(().into())
+		}
+
+		/// Proposes a call which requires approvals from several holders of a role.
+		/// The proposer's approval is counted and `ProposalDeposit` is reserved from the proposer
+		/// until the call is dispatched, cancelled or dropped after `ProposalTimeout` blocks.
+		///
+		/// # Parameters
+		/// - `call`: The runtime call to be dispatched once approved.
+		/// - `with_role`: The role with which the call should be dispatched.
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::propose_call_with_role())]
+		pub fn propose_call_with_role(
+			origin: OriginFor<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+			with_role: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			Self::ensure_can_dispatch_with_role(&who, &with_role, &call_metadata, &call)?;
+			let threshold = Self::required_approvals(&with_role, &call_metadata)
+				.ok_or(Error::<T>::ApprovalNotRequired)?;
+
+			let encoded_call: EncodedCallOf<T> =
+				call.encode().try_into().map_err(|_| Error::<T>::ProposedCallTooLong)?;
+			let call_hash = T::Hashing::hash(&encoded_call);
+			ensure!(
+				!Proposals::<T>::contains_key(&with_role, call_hash),
+				Error::<T>::ProposalAlreadyExists
+			);
+
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			let mut approvals = ApprovalsOf::<T>::default();
+			approvals.try_insert(who.clone()).map_err(|_| Error::<T>::TooManyApprovals)?;
+			let expires_at = System::<T>::block_number().saturating_add(T::ProposalTimeout::get());
+			Proposals::<T>::insert(
+				&with_role,
+				call_hash,
+				CallProposal {
+					proposer: who.clone(),
+					call: encoded_call,
+					approvals,
+					threshold,
+					expires_at,
+					deposit,
+				},
+			);
+			Self::deposit_event(Event::<T>::CallProposed {
+				role_name: with_role,
+				who,
+				call_hash,
+				call_metadata,
+			});
+
+			Ok(().into())
+		}
+
+		/// Approves a proposed call as a holder of the role it was proposed with.
+		/// Once the approval threshold is reached, the proposal is removed, the proposer's
+		/// deposit is released and the call is dispatched with the origin inferred from the role
+		/// for the proposer.
+		///
+		/// # Parameters
+		/// - `role_name`: The role the call was proposed with.
+		/// - `call_hash`: The hash of the encoded proposed call.
+		/// - `max_weight`: The maximum weight the proposed call may consume if this approval
+		///   reaches the threshold.
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::approve_call().saturating_add(*max_weight))]
+		pub fn approve_call(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call_hash: T::Hash,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut proposal =
+				Self::proposal(&role_name, call_hash).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(System::<T>::block_number() < proposal.expires_at, Error::<T>::ProposalExpired);
+			ensure!(Self::holds_role(&who, &role_name), Error::<T>::MissingRole);
+			let call = T::ExtendedRuntimeCall::decode(&mut &proposal.call[..])
+				.map_err(|_| Error::<T>::UndecodableProposedCall)?;
+			ensure!(
+				Self::is_in_scope(&who, &role_name, Some(&call)),
+				Error::<T>::ResourceOutOfScope
+			);
+			ensure!(!proposal.approvals.contains(&who), Error::<T>::AlreadyApproved);
+			proposal
+				.approvals
+				.try_insert(who.clone())
+				.map_err(|_| Error::<T>::TooManyApprovals)?;
+			let approvals = proposal.approvals.len() as u32;
+			Self::deposit_event(Event::<T>::CallApproved {
+				role_name: role_name.clone(),
+				call_hash,
+				who: who.clone(),
+				approvals,
+			});
+
+			if approvals < proposal.threshold {
+				Proposals::<T>::insert(&role_name, call_hash, proposal);
+				return Ok(Some(T::WeightInfo::approve_call()).into())
+			}
+
+			let call_info = call.get_dispatch_info();
+			ensure!(call_info.weight.all_lte(max_weight), Error::<T>::MaxWeightTooLow);
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			let CallProposal { proposer, deposit, .. } = proposal;
+			let role_info =
+				Self::ensure_can_dispatch_with_role(&proposer, &role_name, &call_metadata, &call)?;
+			Proposals::<T>::remove(&role_name, call_hash);
+			T::Currency::unreserve(&proposer, deposit);
+
+			// An approved call exceeding the budget is dropped like a failed call, so that the
+			// proposal is consumed and `RoleBudgetExhausted` is kept.
+			let dispatch_result =
+				match Self::dispatch_as_role(&role_info, &role_name, &proposer, call) {
+					Err(error) if error == Error::<T>::BudgetExceeded.into() => Err(error.into()),
+					result => result?,
+				};
+			Self::deposit_event(Event::<T>::ProposedCallDispatched {
+				call_hash,
+				role_name,
+				who: proposer,
+				result: dispatch_result.map(|_| ()).map_err(|e| e.error),
+			});
+
+			Ok(Some(
+				T::WeightInfo::approve_call()
+					.saturating_add(extract_actual_weight(&dispatch_result, &call_info)),
+			)
+			.into())
+		}
+
+		/// Cancels a proposed call and releases the proposer's deposit.
+		/// Only callable by the account which proposed the call.
+		///
+		/// # Parameters
+		/// - `role_name`: The role the call was proposed with.
+		/// - `call_hash`: The hash of the encoded proposed call.
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::cancel_proposal())]
+		pub fn cancel_proposal(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let proposal =
+				Self::proposal(&role_name, call_hash).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(proposal.proposer == who, Error::<T>::NotProposer);
+
+			Proposals::<T>::remove(&role_name, call_hash);
+			T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+			Self::deposit_event(Event::<T>::ProposalCancelled { role_name, call_hash });
+
+			Ok(().into())
+		}
+
+		/// Drops a proposed call which was not approved in time and releases the proposer's
+		/// deposit. Callable by any signed account.
+		///
+		/// # Parameters
+		/// - `role_name`: The role the call was proposed with.
+		/// - `call_hash`: The hash of the encoded proposed call.
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::remove_expired_proposal())]
+		pub fn remove_expired_proposal(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let proposal =
+				Self::proposal(&role_name, call_hash).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(
+				System::<T>::block_number() >= proposal.expires_at,
+				Error::<T>::ProposalNotExpired
+			);
+
+			Proposals::<T>::remove(&role_name, call_hash);
+			T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+			Self::deposit_event(Event::<T>::ProposalTimedOut { role_name, call_hash });
+
+			Ok(().into())
+		}
+
+		/// Accepts being impersonated by the holders of a role dispatching calls as the caller.
+		/// Calls cannot be dispatched with such a role until the impersonation is accepted.
+		/// The consent cannot change while the role is being renamed.
+		/// Only callable by the account the role dispatches calls as.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role dispatching calls as the caller.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::accept_impersonation())]
+		pub fn accept_impersonation(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			Self::set_impersonation_consent(&role_name, &who, ImpersonationConsent::Given)?;
+			Self::deposit_event(Event::<T>::ImpersonationAccepted { role_name, who });
+
+			Ok(().into())
+		}
+
+		/// Revokes the consent to be impersonated by the holders of a role dispatching calls as
+		/// the caller. Calls can no longer be dispatched with the role until the impersonation is
+		/// accepted again. The consent cannot change while the role is being renamed.
+		/// Only callable by the account the role dispatches calls as.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role dispatching calls as the caller.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::revoke_impersonation())]
+		pub fn revoke_impersonation(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			Self::set_impersonation_consent(&role_name, &who, ImpersonationConsent::Revoked)?;
+			Self::deposit_event(Event::<T>::ImpersonationRevoked { role_name, who });
+
+			Ok(().into())
+		}
+
+		/// Sets or clears the rate limit of a role, capping how many calls can be dispatched using
+		/// the role within a period of blocks. The rate limit cannot change while the role is
+		/// being renamed.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `rate_limit`: The rate limit to set, or `None` to lift the limit.
+		#[pallet::call_index(14)]
+		#[pallet::weight(T::WeightInfo::set_role_rate_limit())]
+		pub fn set_role_rate_limit(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			rate_limit: Option<RateLimit>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			if let Some(rate_limit) = &rate_limit {
+				ensure!(rate_limit.is_valid(), Error::<T>::InvalidRateLimit);
+			}
+
+			Roles::<T>::try_mutate(&role_name, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::RoleDoesNotExist)?;
+				info.set_rate_limit(rate_limit);
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::RoleRateLimitSet { role_name, rate_limit });
+
+			Ok(().into())
+		}
+
+		/// Sets or clears the spending budget of a role, capping the amount of funds that can be
+		/// moved by calls dispatched using the role within a period of blocks. Periods are
+		/// measured in blocks rather than eras, starting at every multiple of the period length.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `budget`: The spending budget to set, or `None` to lift the cap.
+		#[pallet::call_index(15)]
+		#[pallet::weight(T::WeightInfo::set_role_budget())]
+		pub fn set_role_budget(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			budget: Option<SpendingBudgetOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			match &budget {
+				Some(budget) => {
+					ensure!(!budget.period.is_zero(), Error::<T>::InvalidBudget);
+					RoleBudgets::<T>::insert(&role_name, budget);
+				},
+				None => RoleBudgets::<T>::remove(&role_name),
+			}
+			RoleSpending::<T>::remove(&role_name);
+			Self::deposit_event(Event::<T>::RoleBudgetSet { role_name, budget });
+
+			Ok(().into())
+		}
+
+		/// Sets or clears the membership rule of a role. Every account satisfying the rule holds
+		/// the role without being assigned to it, as long as the rule is satisfied.
+		/// Roles that are, or inherit from, roles with exclusive roles cannot have a rule, and the
+		/// rule cannot change while the role is being renamed.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to modify.
+		/// - `rule`: The membership rule to set, or `None` to stop granting the role automatically.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::set_role_membership_rule())]
+		pub fn set_role_membership_rule(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			rule: Option<MembershipRuleOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+			ensure!(
+				rule.is_none() || !Self::has_exclusive_roles(&role_name),
+				Error::<T>::RuleBasedExclusiveRole
+			);
+
+			RoleMembershipRules::<T>::try_mutate(|rules| {
+				match &rule {
+					Some(rule) => {
+						rules
+							.try_insert(role_name.clone(), rule.clone())
+							.map_err(|_| Error::<T>::TooManyRuleBasedRoles)?;
+					},
+					None => {
+						rules.remove(&role_name);
+					},
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::RoleMembershipRuleSet { role_name, rule });
+
+			Ok(().into())
+		}
+
+		/// Denies a call to a role.
+		/// Accounts holding the role, or any role inheriting from it, will not be able to execute
+		/// the call, regardless of the permissions granted by their other roles and even if the
+		/// call is not attached to any role.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to deny the call to.
+		/// - `call`: The call to deny; only its metadata is taken into account.
+		#[pallet::call_index(16)]
+		#[pallet::weight(T::WeightInfo::add_role_denial())]
+		pub fn add_role_denial(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			CallDeniedRoles::<T>::mutate(&call_metadata, |denied_roles| {
+				let denied_roles = denied_roles.get_or_insert(CallRolesListOf::<T>::default());
+				ensure!(!denied_roles.contains(&role_name), Error::<T>::CallAlreadyDenied);
+				denied_roles
+					.try_insert(role_name.clone())
+					.map_err(|_| Error::<T>::TooManyRolesPerCall)
+			})?;
+			Self::inc_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::CallDeniedToRole { role_name, call_metadata });
+
+			Ok(().into())
+		}
+
+		/// Removes a call from the calls denied to a role.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role the call is denied to.
+		/// - `call`: The denied call; only its metadata is taken into account.
+		#[pallet::call_index(17)]
+		#[pallet::weight(T::WeightInfo::remove_role_denial())]
+		pub fn remove_role_denial(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			CallDeniedRoles::<T>::mutate(&call_metadata, |denied_roles| {
+				if let Some(denied_roles) = denied_roles.as_mut() {
+					ensure!(denied_roles.remove(&role_name), Error::<T>::CallNotDenied);
+					Ok(())
+				} else {
+					Err(Error::<T>::CallNotDenied)
+				}
+			})?;
+			Self::dec_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::CallDenialRemovedFromRole { role_name, call_metadata });
+
+			Ok(().into())
+		}
+
+		/// Denies a call to an account.
+		/// The account will not be able to execute the call, regardless of the permissions
+		/// granted by its roles and even if the call is not attached to any role.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `who`: The account to deny the call to.
+		/// - `call`: The call to deny; only its metadata is taken into account.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::add_account_denial())]
+		pub fn add_account_denial(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			ensure!(
+				!AccountDenials::<T>::contains_key(&who, &call_metadata),
+				Error::<T>::CallAlreadyDenied
+			);
+			AccountDenials::<T>::insert(&who, &call_metadata, ());
+			Self::deposit_event(Event::<T>::CallDeniedToAccount { who, call_metadata });
+
+			Ok(().into())
+		}
+
+		/// Removes a call from the calls denied to an account.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `who`: The account the call is denied to.
+		/// - `call`: The denied call; only its metadata is taken into account.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::remove_account_denial())]
+		pub fn remove_account_denial(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			AccountDenials::<T>::take(&who, &call_metadata).ok_or(Error::<T>::CallNotDenied)?;
+			Self::deposit_event(Event::<T>::CallDenialRemovedFromAccount { who, call_metadata });
+
+			Ok(().into())
+		}
+
+		/// Sets the enforcement mode, deciding whether calls without any role attached to them
+		/// are open to everyone or forbidden to everyone, apart from public calls. Calls of this
+		/// pallet check their origins themselves and are never forbidden by the mode, so that it
+		/// can always be switched back.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `mode`: The enforcement mode to apply.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::set_enforcement_mode())]
+		pub fn set_enforcement_mode(
+			origin: OriginFor<T>,
+			mode: EnforcementMode,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			ActiveEnforcementMode::<T>::put(mode);
+			Self::deposit_event(Event::<T>::EnforcementModeSet { mode });
+
+			Ok(().into())
+		}
+
+		/// Makes a call public, so that any account can execute it regardless of the enforcement
+		/// mode and of the roles attached to it. Denials still apply to public calls.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `call`: The call to make public; only its metadata is taken into account.
+		#[pallet::call_index(21)]
+		#[pallet::weight(T::WeightInfo::add_public_call())]
+		pub fn add_public_call(
+			origin: OriginFor<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			ensure!(!Self::is_public_call(&call_metadata), Error::<T>::CallAlreadyPublic);
+			PublicCalls::<T>::insert(&call_metadata, ());
+			Self::deposit_event(Event::<T>::PublicCallAdded { call_metadata });
+
+			Ok(().into())
+		}
+
+		/// Removes a call from the public calls.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `call`: The public call; only its metadata is taken into account.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::remove_public_call())]
+		pub fn remove_public_call(
+			origin: OriginFor<T>,
+			call: Box<T::ExtendedRuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+			PublicCalls::<T>::take(&call_metadata).ok_or(Error::<T>::CallNotPublic)?;
+			Self::deposit_event(Event::<T>::PublicCallRemoved { call_metadata });
+
+			Ok(().into())
+		}
+
+		/// Makes two roles mutually exclusive, so that they can never be assigned to the same
+		/// account, either directly or through roles inheriting from them.
+		/// Accounts already holding both roles keep them; such violations are reported by
+		/// `exclusive_role_violations`. Roles held through a membership rule, directly or through
+		/// roles inheriting from them, cannot be made exclusive.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the first role.
+		/// - `other_role_name`: The name of the second role.
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::add_exclusive_roles())]
+		pub fn add_exclusive_roles(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			other_role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+			ensure!(Self::roles(&other_role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			Self::insert_exclusive_roles(&role_name, &other_role_name)?;
+			Self::inc_role_consumers(&role_name)?;
+			Self::inc_role_consumers(&other_role_name)?;
+			Self::deposit_event(Event::<T>::ExclusiveRolesAdded { role_name, other_role_name });
+
+			Ok(().into())
+		}
+
+		/// Makes two mutually exclusive roles assignable to the same account again.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the first role.
+		/// - `other_role_name`: The name of the second role.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::remove_exclusive_roles())]
+		pub fn remove_exclusive_roles(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			other_role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			for (name, other_name) in
+				[(&role_name, &other_role_name), (&other_role_name, &role_name)]
+			{
+				ExclusiveRoles::<T>::mutate_exists(name, |exclusive_roles| {
+					let exclusive_set =
+						exclusive_roles.as_mut().ok_or(Error::<T>::RolesNotExclusive)?;
+					ensure!(exclusive_set.remove(other_name), Error::<T>::RolesNotExclusive);
+					if exclusive_set.is_empty() {
+						*exclusive_roles = None;
+					}
+					Ok::<_, Error<T>>(())
+				})?;
+			}
+			Self::dec_role_consumers(&role_name)?;
+			Self::dec_role_consumers(&other_role_name)?;
+			Self::deposit_event(Event::<T>::ExclusiveRolesRemoved { role_name, other_role_name });
+
+			Ok(().into())
+		}
+
+		/// Creates a new group of accounts, which can be assigned roles as a unit.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `group_name`: The name of the new group.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::create_group())]
+		pub fn create_group(
+			origin: OriginFor<T>,
+			group_name: GroupNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(!GroupMembers::<T>::contains_key(&group_name), Error::<T>::GroupExists);
+
+			GroupMembers::<T>::insert(&group_name, GroupMembersListOf::<T>::default());
+			Self::deposit_event(Event::<T>::GroupCreated { group_name });
+
+			Ok(().into())
+		}
+
+		/// Removes a group. The group must have neither members nor roles assigned to it.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `group_name`: The name of the group to remove.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::remove_group())]
+		pub fn remove_group(
+			origin: OriginFor<T>,
+			group_name: GroupNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			let members = Self::group_members(&group_name).ok_or(Error::<T>::GroupDoesNotExist)?;
+			ensure!(
+				members.is_empty() && !GroupRoles::<T>::contains_key(&group_name),
+				Error::<T>::GroupNotEmpty
+			);
+
+			GroupMembers::<T>::remove(&group_name);
+			Self::deposit_event(Event::<T>::GroupRemoved { group_name });
+
+			Ok(().into())
+		}
+
+		/// Adds an account to a group, granting it the roles assigned to the group.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `group_name`: The name of the group.
+		/// - `who`: The account to add to the group.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::add_group_member())]
+		pub fn add_group_member(
+			origin: OriginFor<T>,
+			group_name: GroupNameOf<T>,
+			who: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			GroupMembers::<T>::try_mutate(&group_name, |members| {
+				let members = members.as_mut().ok_or(Error::<T>::GroupDoesNotExist)?;
+				ensure!(!members.contains(&who), Error::<T>::AlreadyGroupMember);
+				members.try_insert(who.clone()).map_err(|_| Error::<T>::TooManyGroupMembers)
+			})?;
+			for role_name in Self::group_roles(&group_name).unwrap_or_default() {
+				Self::ensure_no_exclusive_roles(&who, &role_name)?;
+			}
+			AccountGroups::<T>::try_mutate(&who, |account_groups| {
+				account_groups
+					.get_or_insert(AccountGroupsListOf::<T>::default())
+					.try_insert(group_name.clone())
+					.map_err(|_| Error::<T>::TooManyGroupsPerAccount)
+			})?;
+			Self::deposit_event(Event::<T>::AccountAddedToGroup { group_name, who });
+
+			Ok(().into())
+		}
+
+		/// Removes an account from a group, revoking the roles it held through the group.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `group_name`: The name of the group.
+		/// - `who`: The account to remove from the group.
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::remove_group_member())]
+		pub fn remove_group_member(
+			origin: OriginFor<T>,
+			group_name: GroupNameOf<T>,
+			who: AccountIdOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			GroupMembers::<T>::try_mutate(&group_name, |members| {
+				let members = members.as_mut().ok_or(Error::<T>::GroupDoesNotExist)?;
+				ensure!(members.remove(&who), Error::<T>::NotGroupMember);
+				Ok::<_, Error<T>>(())
+			})?;
+			AccountGroups::<T>::mutate_exists(&who, |account_groups| {
+				if let Some(groups) = account_groups.as_mut() {
+					groups.remove(&group_name);
+					if groups.is_empty() {
+						*account_groups = None;
+					}
+				}
+			});
+			Self::deposit_event(Event::<T>::AccountRemovedFromGroup { group_name, who });
+
+			Ok(().into())
+		}
+
+		/// Assigns a role to a group, so that every member of the group holds the role.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `group_name`: The name of the group to assign the role to.
+		/// - `role_name`: The name of the role to assign.
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::assign_role_to_group())]
+		pub fn assign_role_to_group(
+			origin: OriginFor<T>,
+			group_name: GroupNameOf<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			Self::do_assign_role_to_group(&group_name, &role_name)?;
+
+			Ok(().into())
+		}
+
+		/// Unassigns a role from a group, revoking it from every member of the group which does
+		/// not hold it otherwise.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `group_name`: The name of the group to unassign the role from.
+		/// - `role_name`: The name of the role to unassign.
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::unassign_role_from_group())]
+		pub fn unassign_role_from_group(
+			origin: OriginFor<T>,
+			group_name: GroupNameOf<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+
+			GroupRoles::<T>::try_mutate_exists(&group_name, |group_roles| {
+				let roles = group_roles.as_mut().ok_or(Error::<T>::RoleNotAssignedToGroup)?;
+				ensure!(roles.remove(&role_name), Error::<T>::RoleNotAssignedToGroup);
+				if roles.is_empty() {
+					*group_roles = None;
+				}
+				Ok::<_, Error<T>>(())
+			})?;
+			RoleGroups::<T>::remove(&role_name, &group_name);
+			Self::dec_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::GroupUnassignedFromRole { role_name, group_name });
+
+			Ok(().into())
+		}
+
+		/// Invites an account to a role. The role is only assigned once the account accepts the
+		/// invitation, which it can do until the invitation expires after `InvitationTimeout`
+		/// blocks.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `who`: The account to invite.
+		/// - `role_name`: The name of the role to invite the account to.
+		#[pallet::call_index(40)]
+		#[pallet::weight(T::WeightInfo::invite_to_role())]
+		pub fn invite_to_role(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			Self::check_role_existance_and_version(&role_name)?;
+			ensure!(
+				!Self::account_roles(&who).unwrap_or_default().contains(&role_name),
+				Error::<T>::RoleAlreadyAssigned
+			);
+			ensure!(
+				!Invitations::<T>::contains_key(&who, &role_name),
+				Error::<T>::InvitationAlreadyExists
+			);
+			Self::ensure_no_exclusive_roles(&who, &role_name)?;
+
+			let expires_at =
+				System::<T>::block_number().saturating_add(T::InvitationTimeout::get());
+			Invitations::<T>::insert(&who, &role_name, expires_at);
+			Self::inc_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::RoleInvitationSent { role_name, who, expires_at });
+
+			Ok(().into())
+		}
+
+		/// Accepts an invitation to a role, assigning the role to the caller.
+		/// Only callable by the invited account before the invitation expires.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role the caller was invited to.
+		#[pallet::call_index(41)]
+		#[pallet::weight(T::WeightInfo::accept_role_invitation())]
+		pub fn accept_role_invitation(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let expires_at =
+				Invitations::<T>::take(&who, &role_name).ok_or(Error::<T>::InvitationNotFound)?;
+			ensure!(System::<T>::block_number() < expires_at, Error::<T>::InvitationExpired);
+
+			Self::dec_role_consumers(&role_name)?;
+			Self::do_assign_role(&who, &role_name)?;
+			Self::deposit_event(Event::<T>::RoleInvitationAccepted { role_name, who });
+
+			Ok(().into())
+		}
+
+		/// Declines an invitation to a role, whether it has expired or not.
+		/// Only callable by the invited account.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role the caller was invited to.
+		#[pallet::call_index(42)]
+		#[pallet::weight(T::WeightInfo::decline_role_invitation())]
+		pub fn decline_role_invitation(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Invitations::<T>::take(&who, &role_name).ok_or(Error::<T>::InvitationNotFound)?;
+
+			Self::dec_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::RoleInvitationDeclined { role_name, who });
+
+			Ok(().into())
+		}
+
+		/// Cancels an invitation of an account to a role, whether it has expired or not.
+		/// Only callable by accounts with the appropriate management origin or by holders of the
+		/// role's admin role.
+		///
+		/// # Parameters
+		/// - `who`: The invited account.
+		/// - `role_name`: The name of the role the account was invited to.
+		#[pallet::call_index(43)]
+		#[pallet::weight(T::WeightInfo::cancel_role_invitation())]
+		pub fn cancel_role_invitation(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			Invitations::<T>::take(&who, &role_name).ok_or(Error::<T>::InvitationNotFound)?;
+
+			Self::dec_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::RoleInvitationCancelled { role_name, who });
+
+			Ok(().into())
+		}
+
+		/// Removes an invitation which was not accepted in time, releasing its hold on the role.
+		/// Callable by any signed account.
+		///
+		/// # Parameters
+		/// - `who`: The invited account.
+		/// - `role_name`: The name of the role the account was invited to.
+		#[pallet::call_index(56)]
+		#[pallet::weight(T::WeightInfo::remove_expired_invitation())]
+		pub fn remove_expired_invitation(
+			origin: OriginFor<T>,
+			who: AccountIdOf<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let expires_at =
+				Self::invitation(&who, &role_name).ok_or(Error::<T>::InvitationNotFound)?;
+			ensure!(System::<T>::block_number() >= expires_at, Error::<T>::InvitationNotExpired);
+
+			Invitations::<T>::remove(&who, &role_name);
+			Self::dec_role_consumers(&role_name)?;
+			Self::deposit_event(Event::<T>::RoleInvitationExpired { role_name, who });
+
+			Ok(().into())
+		}
+
+		/// Unassigns a role from the caller, giving up its permissions defined for the role.
+		/// Only callable by an account the role is assigned to. Roles held through groups or
+		/// membership rules cannot be renounced.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to renounce.
+		#[pallet::call_index(44)]
+		#[pallet::weight(T::WeightInfo::renounce_role())]
+		pub fn renounce_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::do_unassign_role(&who, &role_name)?;
+
+			Ok(().into())
+		}
+
+		/// Unassigns every role assigned to the caller.
+		/// Only callable by an account with at least one role assigned to it.
+		#[pallet::call_index(45)]
+		#[pallet::weight(T::WeightInfo::renounce_all_roles())]
+		pub fn renounce_all_roles(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let account_roles = Self::account_roles(&who).unwrap_or_default();
+			ensure!(!account_roles.is_empty(), Error::<T>::MissingRole);
+
+			for role_name in account_roles {
+				Self::do_unassign_role(&who, &role_name)?;
+			}
+
+			Ok(().into())
+		}
+
+		/// Suspends a role, so that it grants no permissions to its holders until it is resumed,
+		/// while keeping its assignments and permissions in place. A role cannot be suspended
+		/// while it is being renamed.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to suspend.
+		#[pallet::call_index(46)]
+		#[pallet::weight(T::WeightInfo::suspend_role())]
+		pub fn suspend_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+
+			Roles::<T>::try_mutate(&role_name, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::RoleDoesNotExist)?;
+				ensure!(!info.is_suspended(), Error::<T>::RoleAlreadySuspended);
+				info.set_suspended(true);
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::RoleSuspended { role_name });
+
+			Ok(().into())
+		}
+
+		/// Resumes a suspended role, restoring the permissions of its holders. A role cannot be
+		/// resumed while it is being renamed.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to resume.
+		#[pallet::call_index(47)]
+		#[pallet::weight(T::WeightInfo::resume_role())]
+		pub fn resume_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+
+			ensure!(!RoleRemovals::<T>::contains_key(&role_name), Error::<T>::RoleBeingRemoved);
+			Roles::<T>::try_mutate(&role_name, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::RoleDoesNotExist)?;
+				ensure!(info.is_suspended(), Error::<T>::RoleNotSuspended);
+				info.set_suspended(false);
+				Ok::<_, Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::RoleResumed { role_name });
+
+			Ok(().into())
+		}
+
+		/// Pauses role-based dispatch in an emergency, such as a suspected compromise of a role.
+		/// While paused, no call can be dispatched using a role. Overrides any previous pause.
+		/// Only callable by accounts with the appropriate pause origin.
+		///
+		/// # Parameters
+		/// - `block_extension`: Whether the `CheckRole` extension should also reject every call
+		///   with roles attached to it.
+		/// - `until`: The block at which the pause is lifted automatically, if any.
+		#[pallet::call_index(48)]
+		#[pallet::weight(T::WeightInfo::pause_role_dispatch())]
+		pub fn pause_role_dispatch(
+			origin: OriginFor<T>,
+			block_extension: bool,
+			until: Option<BlockNumberFor<T>>,
+		) -> DispatchResultWithPostInfo {
+			T::PauseOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			let now = System::<T>::block_number();
+			ensure!(until.map_or(true, |until| until > now), Error::<T>::InvalidPauseEnd);
+
+			DispatchPause::<T>::put(PauseState { block_extension, until });
+			Self::deposit_event(Event::<T>::RoleDispatchPaused { block_extension, until });
+
+			Ok(().into())
+		}
+
+		/// Lifts the emergency pause of role-based dispatch, or removes a pause which already
+		/// ended at its end block.
+		/// Only callable by accounts with the appropriate pause origin.
+		#[pallet::call_index(49)]
+		#[pallet::weight(T::WeightInfo::unpause_role_dispatch())]
+		pub fn unpause_role_dispatch(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			T::PauseOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			DispatchPause::<T>::take().ok_or(Error::<T>::DispatchNotPaused)?;
+			Self::deposit_event(Event::<T>::RoleDispatchUnpaused);
+
+			Ok(().into())
+		}
+
+		/// Forces the removal of a role which is still in use, unassigning it from all accounts and
+		/// groups and dropping every permission, denial, invitation, parent and admin link
+		/// referring to it along with the rate limit usage counted for it. Each call visits at most
+		/// `RemovalStepsLimit` storage entries and saves its progress, so the call has to
+		/// be repeated until the role is removed. The role is suspended by the first call
+		/// and cannot be resumed, assigned or granted anything afterwards.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to remove.
+		#[pallet::call_index(50)]
+		#[pallet::weight(T::WeightInfo::force_remove_role(T::RemovalStepsLimit::get()))]
+		pub fn force_remove_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			ensure!(!RoleCloneTargets::<T>::contains_key(&role_name), Error::<T>::RoleBeingCloned);
+
+			let cursor = match Self::role_removal(&role_name) {
+				Some(cursor) => cursor,
+				None => {
+					Roles::<T>::try_mutate(&role_name, |info| {
+						let info = info.as_mut().ok_or(Error::<T>::RoleDoesNotExist)?;
+						info.set_suspended(true);
+						Ok::<_, Error<T>>(())
+					})?;
+					Self::deposit_event(Event::<T>::RoleRemovalStarted {
+						role_name: role_name.clone(),
+					});
+					RoleRewriteCursor::default()
+				},
+			};
+			let limit = T::RemovalStepsLimit::get();
+			match Self::advance_role_rewrite(&role_name, None, cursor, limit)? {
+				Some(cursor) => RoleRemovals::<T>::insert(&role_name, cursor),
+				None => Self::finish_role_rewrite(role_name, None)?,
+			}
+
+			Ok(().into())
+		}
+
+		/// Renames a role, rewriting every reference to it: its assignments to accounts and groups,
+		/// its permissions, denials, invitations, proposals, parent, admin and exclusion links,
+		/// along with its budget, metadata, rate limit usage and membership rule. The role keeps
+		/// its settings and consumers counter under the new name. The references are moved in the
+		/// same way as `force_remove_role` drops them, so the call has to be repeated with the same
+		/// names until the role is renamed; in the meantime, both names grant the role's
+		/// permissions and share its budget, and neither can be removed, updated or cloned.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to rename.
+		/// - `new_role_name`: The new name of the role.
+		#[pallet::call_index(51)]
+		#[pallet::weight(T::WeightInfo::rename_role(T::RemovalStepsLimit::get()))]
+		pub fn rename_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			new_role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			ensure!(!RoleRemovals::<T>::contains_key(&role_name), Error::<T>::RoleBeingRemoved);
+			ensure!(
+				!RoleClones::<T>::contains_key(&role_name) &&
+					!RoleCloneTargets::<T>::contains_key(&role_name),
+				Error::<T>::RoleBeingCloned
+			);
+
+			let cursor = match Self::role_rename(&role_name) {
+				Some(rename) => {
+					ensure!(rename.new_role_name == new_role_name, Error::<T>::RoleBeingRenamed);
+					rename.cursor
+				},
+				None => {
+					ensure!(
+						!RoleRenameSources::<T>::contains_key(&role_name),
+						Error::<T>::RoleBeingRenamed
+					);
+					Self::start_role_rename(&role_name, &new_role_name)?;
+					RoleRewriteCursor::default()
+				},
+			};
+			Self::advance_role_rename(role_name, new_role_name, cursor)?;
+
+			Ok(().into())
+		}
+
+		/// Updates the settings of a role, i.e. whether it bypasses call filters and the origin it
+		/// dispatches calls with, keeping its assignments and permissions. Impersonating another
+		/// account requires the consent of that account again. Settings equal to the current ones
+		/// leave the role unchanged. A role cannot be updated while it is being renamed.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to update.
+		/// - `new_settings`: The new settings of the role.
+		#[pallet::call_index(52)]
+		#[pallet::weight(T::WeightInfo::update_role())]
+		pub fn update_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			new_settings: RoleSettingsOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+
+			let old_settings = Roles::<T>::try_mutate(&role_name, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::RoleDoesNotExist)?;
+				let old_settings = info.settings();
+				if old_settings != new_settings {
+					info.set_settings(new_settings.clone());
+				}
+				Ok::<_, Error<T>>(old_settings)
+			})?;
+			if old_settings != new_settings {
+				Self::deposit_event(Event::<T>::RoleUpdated {
+					role_name,
+					old_settings,
+					new_settings,
+				});
+			}
+
+			Ok(().into())
+		}
+
+		/// Sets or clears the human-readable description of a role, i.e. its description, owner,
+		/// policy document URI and tags. Only callable by accounts with the appropriate management
+		/// origin or holding the role's admin role.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to describe.
+		/// - `metadata`: The metadata to set, or `None` to clear it.
+		#[pallet::call_index(53)]
+		#[pallet::weight(T::WeightInfo::set_role_metadata())]
+		pub fn set_role_metadata(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			metadata: Option<RoleMetadataOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_role_manager(origin, &role_name)?;
+			ensure!(Self::roles(&role_name).is_some(), Error::<T>::RoleDoesNotExist);
+
+			RolesMetadata::<T>::set(&role_name, metadata.clone());
+			Self::deposit_event(Event::<T>::RoleMetadataSet { role_name, metadata });
+
+			Ok(().into())
+		}
+
+		/// Applies several policy changes, such as creating roles, attaching calls to them and
+		/// assigning them, as a whole. If any change fails, none of them is applied.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `changes`: The changes to apply, in order.
+		#[pallet::call_index(54)]
+		#[pallet::weight(Pallet::<T>::policy_changes_weight(changes))]
+		pub fn apply_policy_changes(
+			origin: OriginFor<T>,
+			changes: PolicyChangesOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin.clone()).map_err(|_| Error::<T>::BadOrigin)?;
+
+			let count = changes.len() as u32;
+			for change in changes {
+				match change {
+					PolicyChange::CreateRole {
+						role_name,
+						allow_filter_bypassing,
+						allow_dispatch_as,
+					} => Self::create_role(
+						origin.clone(),
+						role_name,
+						allow_filter_bypassing,
+						allow_dispatch_as,
+					),
+					PolicyChange::AddCall { role_name, call } =>
+						Self::add_call(origin.clone(), role_name, call),
+					PolicyChange::RemoveCall { role_name, call } =>
+						Self::remove_call(origin.clone(), role_name, call),
+					PolicyChange::AssignRole { who, role_name } =>
+						Self::assign_role(origin.clone(), who, role_name),
+					PolicyChange::UnassignRole { who, role_name } =>
+						Self::unassign_role(origin.clone(), who, role_name),
+				}?;
+			}
+			Self::deposit_event(Event::<T>::PolicyChangesApplied { count });
+
+			Ok(().into())
+		}
+
+		/// Creates a new role with the settings, rate limit, budget, parent roles, exclusive roles,
+		/// admin role and membership rule of another role, granting it every call and pallet
+		/// wildcard granted to the other role, along with the constraints and approval thresholds
+		/// of the grants, and denying it every call denied to the other role. Optionally, the new
+		/// role is also assigned to every account the other role is assigned to, with the same
+		/// validity period and scope, and to every group the other role is assigned to. The grants
+		/// and assignments are copied in the same way as `rename_role` moves them, so the call has
+		/// to be repeated with the same arguments until the role is cloned.
+		/// A suspended role cannot be cloned, and a cloning does not progress while the role is
+		/// suspended. Until the cloning is complete, the role cannot be cloned again, renamed or
+		/// removed; removing the new role abandons the cloning.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role to clone.
+		/// - `new_role_name`: The name of the new role.
+		/// - `with_members`: Whether to assign the new role to the accounts holding the role.
+		#[pallet::call_index(55)]
+		#[pallet::weight(T::WeightInfo::clone_role(T::RemovalStepsLimit::get()))]
+		pub fn clone_role(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+			new_role_name: RoleNameOf<T>,
+			with_members: bool,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+			Self::check_role_existance_and_version(&role_name)?;
+			Self::ensure_not_being_renamed(&role_name)?;
+			ensure!(!RoleClones::<T>::contains_key(&role_name), Error::<T>::RoleBeingCloned);
+
+			let cursor = match Self::role_clone(&new_role_name) {
+				Some(clone) => {
+					ensure!(
+						clone.source_role_name == role_name && clone.with_members == with_members,
+						Error::<T>::RoleBeingCloned
+					);
+					clone.cursor
+				},
+				None => {
+					ensure!(
+						!RoleCloneTargets::<T>::contains_key(&role_name),
+						Error::<T>::RoleBeingCloned
+					);
+					Self::start_role_clone(&role_name, &new_role_name)?;
+					RoleRewriteCursor::default()
+				},
+			};
+			let limit = T::RemovalStepsLimit::get();
+			let cursor =
+				Self::advance_role_clone(&role_name, &new_role_name, with_members, cursor, limit)?;
+			match cursor {
+				Some(cursor) => RoleClones::<T>::insert(
+					&new_role_name,
+					RoleClone { source_role_name: role_name, with_members, cursor },
+				),
+				None => {
+					RoleClones::<T>::remove(&new_role_name);
+					RoleCloneTargets::<T>::remove(&role_name);
+					Self::deposit_event(Event::<T>::RoleCloned { role_name, new_role_name });
+				},
+			}
+
+			Ok(().into())
+		}
+
+		/// Cancels the renaming of a role, renaming it back from its new name instead. The
+		/// references moved to the new name so far are moved back in the same way as
+		/// `rename_role` moves them, so the call has to be repeated with the same name until the
+		/// role is renamed back and its new name is removed.
+		/// Only callable by accounts with the appropriate management origin.
+		///
+		/// # Parameters
+		/// - `role_name`: The name of the role being renamed.
+		#[pallet::call_index(57)]
+		#[pallet::weight(T::WeightInfo::cancel_role_rename(T::RemovalStepsLimit::get()))]
+		pub fn cancel_role_rename(
+			origin: OriginFor<T>,
+			role_name: RoleNameOf<T>,
+		) -> DispatchResultWithPostInfo {
+			T::ManageOrigin::ensure_origin(origin).map_err(|_| Error::<T>::BadOrigin)?;
+
+			let (new_role_name, cursor) = match Self::role_rename(&role_name) {
+				Some(rename) => {
+					RoleRenames::<T>::remove(&role_name);
+					RoleRenameSources::<T>::remove(&rename.new_role_name);
+					RoleRenameSources::<T>::insert(&role_name, &rename.new_role_name);
+					Self::deposit_event(Event::<T>::RoleRenameCancelled {
+						role_name: role_name.clone(),
+						new_role_name: rename.new_role_name.clone(),
+					});
+					(rename.new_role_name, RoleRewriteCursor::default())
+				},
+				None => {
+					let new_role_name = Self::role_rename_source(&role_name)
+						.ok_or(Error::<T>::RoleNotBeingRenamed)?;
+					let rename =
+						Self::role_rename(&new_role_name).ok_or(Error::<T>::RoleNotBeingRenamed)?;
+					(new_role_name, rename.cursor)
+				},
+			};
+			Self::advance_role_rename(new_role_name, role_name, cursor)?;
+
+			Ok(().into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Computes the weight of applying a batch of policy changes as the sum of the weights of
+	/// the individual changes.
+	///
+	/// # Parameters
+	/// - `changes`: The changes to apply.
+	pub fn policy_changes_weight(changes: &PolicyChangesOf<T>) -> Weight {
+		changes.iter().fold(Weight::zero(), |weight, change| {
+			weight.saturating_add(match change {
+				PolicyChange::CreateRole { .. } => T::WeightInfo::apply_create_role_change(),
+				PolicyChange::AddCall { .. } => T::WeightInfo::apply_add_call_change(),
+				PolicyChange::RemoveCall { .. } => T::WeightInfo::apply_remove_call_change(),
+				PolicyChange::AssignRole { .. } => T::WeightInfo::apply_assign_role_change(),
+				PolicyChange::UnassignRole { .. } => T::WeightInfo::apply_unassign_role_change(),
+			})
+		})
+	}
+
+	/// Internal utility function to increase the consumer counter for a role. A role being
+	/// force-removed cannot gain new consumers, as they would make its removal start over.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to increment the consumer counter for.
+	pub fn inc_role_consumers(role_name: &RoleNameOf<T>) -> DispatchResult {
+		ensure!(!RoleRemovals::<T>::contains_key(role_name), Error::<T>::RoleBeingRemoved);
+		Roles::<T>::mutate(role_name, |info| {
+			if let Some(info) = info.as_mut() {
+				info.inc_consumers()
+			} else {
+				Err(Error::<T>::RoleDoesNotExist.into())
+			}
+		})
+	}
+
+	/// Internal utility function to decrease the consumer counter for a role.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to decrement the consumer counter for.
+	pub fn dec_role_consumers(role_name: &RoleNameOf<T>) -> DispatchResult {
+		Roles::<T>::mutate(role_name, |info| {
+			if let Some(info) = info.as_mut() {
+				info.dec_consumers()
+			} else {
+				Err(Error::<T>::RoleDoesNotExist.into())
+			}
+		})
+	}
+
+	/// Checks the existence and version compatibility of a specified role.
+	///
+	/// Given a role name, this function performs three essential checks:
+	///
+	/// 1. It verifies the existence of the role in the storage. If the role does not exist, it
+	///    returns a `RoleDoesNotExist` error.
+	/// 2. It verifies that the role is not suspended. If it is, it returns a `RoleSuspended` error,
+	///    or a `RoleBeingRemoved` error if the role is suspended because it is being force-removed.
+	/// 3. It verifies the compatibility of the role's version with the current runtime version. If
+	///    the versions are incompatible, it returns a custom error.
+	///
+	/// # Parameters
+	/// - `role_name`: A reference to the name of the role to check.
+	pub fn check_role_existance_and_version(
+		role_name: &RoleNameOf<T>,
+	) -> Result<RoleInfoOf<T>, DispatchError> {
+		let role_info = Self::roles(role_name).ok_or(Error::<T>::RoleDoesNotExist)?;
+		ensure!(
+			!role_info.is_suspended(),
+			if RoleRemovals::<T>::contains_key(role_name) {
+				Error::<T>::RoleBeingRemoved
+			} else {
+				Error::<T>::RoleSuspended
+			}
+		);
+		let current_version = System::<T>::runtime_version();
+		role_info.check_version(current_version)?;
+		Ok(role_info)
+	}
+
+	/// Ensures that the origin is allowed to manage a role, i.e. it is either the management
+	/// origin or a signed origin of an account holding the role's admin role without a scope.
+	/// Roles dispatching calls as root or as another account, or bypassing the call filter, can
+	/// only be managed by the management origin.
+	///
+	/// # Parameters
+	/// - `origin`: The origin to check.
+	/// - `role_name`: The name of the role to be managed.
+	pub fn ensure_role_manager(
+		origin: T::RuntimeOrigin,
+		role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		let origin = match T::ManageOrigin::try_origin(origin) {
+			Ok(_) => return Ok(()),
+			Err(origin) => origin,
+		};
+		let who = frame_system::ensure_signed(origin).map_err(|_| Error::<T>::BadOrigin)?;
+		let admin_role_name = Self::role_admin(role_name).ok_or(Error::<T>::BadOrigin)?;
+		ensure!(Self::holds_role(&who, &admin_role_name), Error::<T>::BadOrigin);
+		ensure!(Self::is_in_scope(&who, &admin_role_name, None), Error::<T>::ResourceOutOfScope);
+		let role_info = Self::roles(role_name).ok_or(Error::<T>::RoleDoesNotExist)?;
+		ensure!(
+			role_info.dispatches_as_holder() && !role_info.allow_filter_bypassing,
+			Error::<T>::PrivilegedRole
+		);
+		Ok(())
+	}
+
+	/// Checks whether an account holds a role, i.e. the role is assigned to the account with the
+	/// assignment in effect or to a group the account is a member of, or the account satisfies
+	/// the role's membership rule, and the role is compatible with the current runtime version.
+	///
+	/// # Parameters
+	/// - `who`: The account to check.
+	/// - `role_name`: The name of the role to look for.
+	pub fn holds_role(who: &AccountIdOf<T>, role_name: &RoleNameOf<T>) -> bool {
+		Self::held_roles(who).contains(role_name) &&
+			Self::check_role_existance_and_version(role_name).is_ok()
+	}
+
+	/// Returns the roles assigned to the groups an account is a member of.
+	///
+	/// # Parameters
+	/// - `who`: The account to check.
+	pub fn account_group_roles(who: &AccountIdOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		Self::account_groups(who)
+			.unwrap_or_default()
+			.iter()
+			.flat_map(|group_name| Self::group_roles(group_name).unwrap_or_default())
+			.collect()
+	}
+
+	/// Returns the roles whose membership rule is satisfied by an account.
+	///
+	/// # Parameters
+	/// - `who`: The account to check.
+	pub fn account_rule_based_roles(who: &AccountIdOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		Self::role_membership_rules()
+			.into_iter()
+			.filter(|(_, rule)| T::MembershipRules::is_satisfied(rule, who))
+			.map(|(role_name, _)| role_name)
+			.collect()
+	}
+
+	/// Returns the roles an account holds without being assigned to them, i.e. the roles
+	/// assigned to its groups and the roles whose membership rule it satisfies.
+	///
+	/// # Parameters
+	/// - `who`: The account to check.
+	pub fn account_implicit_roles(who: &AccountIdOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		let mut implicit_roles = Self::account_group_roles(who);
+		implicit_roles.extend(Self::account_rule_based_roles(who));
+		implicit_roles
+	}
+
+	/// Returns the roles an account holds, i.e. the roles assigned to the account whose
+	/// assignment is in effect together with the roles it holds implicitly. The version of the
+	/// roles is not checked.
+	///
+	/// # Parameters
+	/// - `who`: The account to check.
+	pub fn held_roles(who: &AccountIdOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		let mut held_roles: BTreeSet<RoleNameOf<T>> = Self::account_roles(who)
+			.unwrap_or_default()
+			.into_iter()
+			.filter(|role_name| Self::is_assignment_active(who, role_name))
+			.collect();
+		held_roles.extend(Self::account_implicit_roles(who));
+		held_roles
+	}
+
+	/// Removes a role along with its settings which are not counted as consumers of the role.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to remove.
+	fn do_remove_role(role_name: RoleNameOf<T>) {
+		Roles::<T>::remove(&role_name);
+		RoleBudgets::<T>::remove(&role_name);
+		RolesMetadata::<T>::remove(&role_name);
+		RoleSpending::<T>::remove(&role_name);
+		RoleUsage::<T>::remove(&role_name);
+		RoleRemovals::<T>::remove(&role_name);
+		if let Some(clone) = RoleClones::<T>::take(&role_name) {
+			RoleCloneTargets::<T>::remove(&clone.source_role_name);
+		}
+		RoleMembershipRules::<T>::mutate(|rules| rules.remove(&role_name));
+		Self::deposit_event(Event::<T>::RoleRemoved { role_name });
+	}
+
+	/// Copies the settings of a role to its new name at the start of a renaming, so that the new
+	/// name grants the role's permissions to the holders whose references are already moved. The
+	/// budget and metadata of the role are only moved once the role is renamed.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being renamed.
+	/// - `new_role_name`: The new name of the role.
+	fn start_role_rename(
+		role_name: &RoleNameOf<T>,
+		new_role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		let role_info = Self::roles(role_name).ok_or(Error::<T>::RoleDoesNotExist)?;
+		ensure!(!Roles::<T>::contains_key(new_role_name), Error::<T>::RoleExists);
+
+		Roles::<T>::insert(new_role_name, role_info.without_consumers());
+		RoleRenameSources::<T>::insert(new_role_name, role_name);
+		if let Some(rule) = Self::role_membership_rules().get(role_name).cloned() {
+			RoleMembershipRules::<T>::try_mutate(|rules| {
+				rules
+					.try_insert(new_role_name.clone(), rule)
+					.map_err(|_| Error::<T>::TooManyRuleBasedRoles)
+			})?;
+		}
+		Self::deposit_event(Event::<T>::RoleRenameStarted {
+			role_name: role_name.clone(),
+			new_role_name: new_role_name.clone(),
+		});
+		Ok(())
+	}
+
+	/// Moves the references to a role being renamed to its new name, visiting at most
+	/// `RemovalStepsLimit` storage entries starting at the cursor, and saves the progress or
+	/// completes the renaming.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being renamed.
+	/// - `new_role_name`: The new name of the role.
+	/// - `cursor`: The progress of the renaming.
+	fn advance_role_rename(
+		role_name: RoleNameOf<T>,
+		new_role_name: RoleNameOf<T>,
+		cursor: RoleRewriteCursorOf,
+	) -> DispatchResult {
+		let limit = T::RemovalStepsLimit::get();
+		match Self::advance_role_rewrite(&role_name, Some(&new_role_name), cursor, limit)? {
+			Some(cursor) =>
+				RoleRenames::<T>::insert(&role_name, RoleRename { new_role_name, cursor }),
+			None => Self::finish_role_rewrite(role_name, Some(new_role_name))?,
+		}
+		Ok(())
+	}
+
+	/// Returns the other name of a role being renamed, i.e. the new name of the role or, for the
+	/// new name, the name the role is being renamed from. Both names refer to the same role until
+	/// the renaming is complete.
+	///
+	/// # Parameters
+	/// - `role_name`: The name to look up.
+	pub fn renamed_role_alias(role_name: &RoleNameOf<T>) -> Option<RoleNameOf<T>> {
+		Self::role_rename(role_name)
+			.map(|rename| rename.new_role_name)
+			.or_else(|| Self::role_rename_source(role_name))
+	}
+
+	/// Ensures that a role is neither being renamed nor the new name of a role being renamed.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to check.
+	fn ensure_not_being_renamed(role_name: &RoleNameOf<T>) -> DispatchResult {
+		ensure!(
+			!RoleRenames::<T>::contains_key(role_name) &&
+				!RoleRenameSources::<T>::contains_key(role_name),
+			Error::<T>::RoleBeingRenamed
+		);
+		Ok(())
+	}
+
+	/// Creates the clone of a role at the start of a cloning, with the role's settings, rate
+	/// limit, budget, parent roles, exclusive roles, admin role and membership rule.
+	/// Impersonating another account requires the consent of that account again. The clone does
+	/// not become the parent or the admin of the roles the role is the parent or the admin of.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being cloned.
+	/// - `new_role_name`: The name of the clone.
+	fn start_role_clone(
+		role_name: &RoleNameOf<T>,
+		new_role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		let role_info = Self::roles(role_name).ok_or(Error::<T>::RoleDoesNotExist)?;
+		ensure!(!Roles::<T>::contains_key(new_role_name), Error::<T>::RoleExists);
+
+		let settings = role_info.settings();
+		let mut new_role_info = RoleInfoOf::<T>::new(
+			System::<T>::runtime_version(),
+			settings.allow_filter_bypassing,
+			settings.dispatch_origin,
+		);
+		new_role_info.set_rate_limit(role_info.rate_limit());
+		Roles::<T>::insert(new_role_name, new_role_info);
+		RoleCloneTargets::<T>::insert(role_name, new_role_name);
+		if let Some(budget) = Self::role_budget(role_name) {
+			RoleBudgets::<T>::insert(new_role_name, budget);
+		}
+		for parent_role_name in Self::role_parents(role_name).unwrap_or_default() {
+			Self::do_add_parent_role(new_role_name, &parent_role_name)?;
+		}
+		for other_role_name in Self::exclusive_roles(role_name).unwrap_or_default() {
+			Self::insert_exclusive_roles(new_role_name, &other_role_name)?;
+			Self::inc_role_consumers(new_role_name)?;
+			Self::inc_role_consumers(&other_role_name)?;
+		}
+		if let Some(admin_role_name) = Self::role_admin(role_name) {
+			RoleAdmins::<T>::insert(new_role_name, &admin_role_name);
+			Self::inc_role_consumers(new_role_name)?;
+			Self::inc_role_consumers(&admin_role_name)?;
+		}
+		if let Some(rule) = Self::role_membership_rules().get(role_name).cloned() {
+			RoleMembershipRules::<T>::try_mutate(|rules| {
+				rules
+					.try_insert(new_role_name.clone(), rule)
+					.map_err(|_| Error::<T>::TooManyRuleBasedRoles)
+			})?;
+		}
+		Self::deposit_event(Event::<T>::RoleCloneStarted {
+			role_name: role_name.clone(),
+			new_role_name: new_role_name.clone(),
+		});
+		Ok(())
+	}
+
+	/// Copies the assignments to accounts and groups, if requested, the grants, pallet wildcards
+	/// and denials of a role being cloned to the clone, visiting at most `limit` storage entries
+	/// starting at the cursor. Returns the updated cursor, or `None` once every stage is
+	/// complete. Assignments which have already expired are not copied.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being cloned.
+	/// - `new_role_name`: The name of the clone.
+	/// - `with_members`: Whether to copy the assignments of the role.
+	/// - `cursor`: The progress of the cloning.
+	/// - `limit`: The maximum number of storage entries to visit.
+	fn advance_role_clone(
+		role_name: &RoleNameOf<T>,
+		new_role_name: &RoleNameOf<T>,
+		with_members: bool,
+		mut cursor: RoleRewriteCursorOf,
+		limit: u32,
+	) -> Result<Option<RoleRewriteCursorOf>, DispatchError> {
+		let now = System::<T>::block_number();
+		let mut remaining = limit;
+		while !remaining.is_zero() {
+			let last_key = cursor.last_key.take().map(|key| key.into_inner());
+			let (visited, last_key) = match cursor.stage {
+				RoleRewriteStage::AccountRoles if with_members => Self::visit_entries(
+					last_key.map_or_else(AccountRoles::<T>::iter, AccountRoles::<T>::iter_from),
+					remaining,
+					|who| AccountRoles::<T>::hashed_key_for(who),
+					|who, account_roles| {
+						let validity = Self::assignment_validity(&who, role_name);
+						if !account_roles.contains(role_name) ||
+							account_roles.contains(new_role_name) ||
+							validity
+								.as_ref()
+								.map_or(false, |validity| validity.is_expired_at(&now))
+						{
+							return Ok(())
+						}
+						Self::do_assign_role(&who, new_role_name)?;
+						if let Some(validity) = validity {
+							Self::set_assignment_validity(&who, new_role_name, validity)?;
+						}
+						if let Some(scopes) = Self::assignment_scopes(&who, role_name) {
+							AssignmentScopes::<T>::insert(&who, new_role_name, scopes);
+						}
+						Ok(())
+					},
+				)?,
+				RoleRewriteStage::CallRoles => Self::visit_entries(
+					last_key.map_or_else(CallRoles::<T>::iter, CallRoles::<T>::iter_from),
+					remaining,
+					|call_metadata| CallRoles::<T>::hashed_key_for(call_metadata),
+					|call_metadata, mut call_roles| {
+						if !Self::clone_role_in_set(&mut call_roles, role_name, new_role_name)? {
+							return Ok(())
+						}
+						CallRoles::<T>::insert(&call_metadata, call_roles);
+						if let Some(constraints) =
+							CallConstraints::<T>::get(&call_metadata, role_name)
+						{
+							CallConstraints::<T>::insert(
+								&call_metadata,
+								new_role_name,
+								constraints,
+							);
+						}
+						if let Some(threshold) =
+							ApprovalThresholds::<T>::get(&call_metadata, role_name)
+						{
+							ApprovalThresholds::<T>::insert(
+								&call_metadata,
+								new_role_name,
+								threshold,
+							);
+						}
+						Ok(())
+					},
+				)?,
+				RoleRewriteStage::CallDeniedRoles => Self::visit_entries(
+					last_key
+						.map_or_else(CallDeniedRoles::<T>::iter, CallDeniedRoles::<T>::iter_from),
+					remaining,
+					|call_metadata| CallDeniedRoles::<T>::hashed_key_for(call_metadata),
+					|call_metadata, mut denied_roles| {
+						if Self::clone_role_in_set(&mut denied_roles, role_name, new_role_name)? {
+							CallDeniedRoles::<T>::insert(&call_metadata, denied_roles);
+						}
+						Ok(())
+					},
+				)?,
+				RoleRewriteStage::PalletRoles => Self::visit_entries(
+					last_key.map_or_else(PalletRoles::<T>::iter, PalletRoles::<T>::iter_from),
+					remaining,
+					|pallet_index| PalletRoles::<T>::hashed_key_for(pallet_index),
+					|pallet_index, mut pallet_roles| {
+						if Self::clone_role_in_set(&mut pallet_roles, role_name, new_role_name)? {
+							PalletRoles::<T>::insert(pallet_index, pallet_roles);
+						}
+						Ok(())
+					},
+				)?,
+				RoleRewriteStage::GroupRoles if with_members => Self::visit_entries(
+					last_key.map_or_else(GroupRoles::<T>::iter, GroupRoles::<T>::iter_from),
+					remaining,
+					|group_name| GroupRoles::<T>::hashed_key_for(group_name),
+					|group_name, group_roles| {
+						if group_roles.contains(role_name) && !group_roles.contains(new_role_name) {
+							Self::do_assign_role_to_group(&group_name, new_role_name)?;
+						}
+						Ok(())
+					},
+				)?,
+				_ => (0, None),
+			};
+			remaining.saturating_reduce(visited);
+			match (last_key, cursor.stage.next()) {
+				(Some(last_key), _) =>
+					cursor.last_key =
+						Some(last_key.try_into().map_err(|_| Error::<T>::StorageKeyTooLong)?),
+				(None, Some(stage)) => cursor = RoleRewriteCursor { stage, last_key: None },
+				(None, None) => return Ok(None),
+			}
+		}
+		Ok(Some(cursor))
+	}
+
+	/// Inserts the clone of a role into a set of roles containing the role, increasing the
+	/// clone's consumers counter. Returns whether the clone was inserted, which is not the case if
+	/// the role is not in the set or the clone already is.
+	///
+	/// # Parameters
+	/// - `roles`: The set of roles to extend.
+	/// - `role_name`: The name of the role being cloned.
+	/// - `new_role_name`: The name of the clone.
+	fn clone_role_in_set<S: Get<u32>>(
+		roles: &mut BoundedBTreeSet<RoleNameOf<T>, S>,
+		role_name: &RoleNameOf<T>,
+		new_role_name: &RoleNameOf<T>,
+	) -> Result<bool, DispatchError> {
+		if !roles.contains(role_name) || roles.contains(new_role_name) {
+			return Ok(false)
+		}
+		roles
+			.try_insert(new_role_name.clone())
+			.map_err(|_| Error::<T>::TooManyRolesPerCall)?;
+		Self::inc_role_consumers(new_role_name)?;
+		Ok(true)
+	}
+
+	/// Rewrites the references to a role being force-removed or renamed, visiting at most `limit`
+	/// storage entries starting at the cursor. Returns the updated cursor, or `None` once every
+	/// stage is complete.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being removed or renamed.
+	/// - `new_role_name`: The new name of the role, or `None` if it is being removed.
+	/// - `cursor`: The progress of the removal or renaming.
+	/// - `limit`: The maximum number of storage entries to visit.
+	fn advance_role_rewrite(
+		role_name: &RoleNameOf<T>,
+		new_role_name: Option<&RoleNameOf<T>>,
+		mut cursor: RoleRewriteCursorOf,
+		limit: u32,
+	) -> Result<Option<RoleRewriteCursorOf>, DispatchError> {
+		let mut remaining = limit;
+		while !remaining.is_zero() {
+			let last_key = cursor.last_key.take().map(|key| key.into_inner());
+			let (visited, last_key) = Self::rewrite_role_references(
+				role_name,
+				new_role_name,
+				cursor.stage,
+				last_key,
+				remaining,
+			)?;
+			remaining.saturating_reduce(visited);
+			match (last_key, cursor.stage.next()) {
+				(Some(last_key), _) =>
+					cursor.last_key =
+						Some(last_key.try_into().map_err(|_| Error::<T>::StorageKeyTooLong)?),
+				(None, Some(stage)) => cursor = RoleRewriteCursor { stage, last_key: None },
+				(None, None) => return Ok(None),
+			}
+		}
+		Ok(Some(cursor))
+	}
+
+	/// Completes a forced removal or a renaming of a role once every stage is complete, after
+	/// rewriting its mutual exclusions. The role is removed, or its remaining settings are moved
+	/// to its new name. If the role is still in use, e.g. because it was granted a permission
+	/// behind the cursor in the meantime, the removal or renaming starts over instead.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being removed or renamed.
+	/// - `new_role_name`: The new name of the role, or `None` if it is being removed.
+	fn finish_role_rewrite(
+		role_name: RoleNameOf<T>,
+		new_role_name: Option<RoleNameOf<T>>,
+	) -> DispatchResult {
+		for other_role_name in ExclusiveRoles::<T>::take(&role_name).unwrap_or_default() {
+			let replaced =
+				ExclusiveRoles::<T>::try_mutate_exists(&other_role_name, |exclusive_roles| {
+					let replaced = match exclusive_roles.as_mut() {
+						Some(exclusive_set) => Self::rewrite_role_in_set(
+							exclusive_set,
+							&role_name,
+							new_role_name.as_ref(),
+						)?,
+						None => None,
+					};
+					if exclusive_roles
+						.as_ref()
+						.map_or(false, |exclusive_set| exclusive_set.is_empty())
+					{
+						*exclusive_roles = None;
+					}
+					Ok::<_, DispatchError>(replaced)
+				})?;
+			match (replaced, new_role_name.as_ref()) {
+				(Some(true), Some(new_role_name)) =>
+					ExclusiveRoles::<T>::try_mutate(new_role_name, |exclusive_roles| {
+						exclusive_roles
+							.get_or_insert(ExclusiveRolesListOf::<T>::default())
+							.try_insert(other_role_name)
+							.map(|_| ())
+							.map_err(|_| Error::<T>::TooManyExclusiveRoles)
+					})?,
+				_ => Self::dec_role_consumers(&other_role_name)?,
+			}
+		}
+
+		let role_info = Self::roles(&role_name).ok_or(Error::<T>::RoleDoesNotExist)?;
+		match new_role_name {
+			None if role_info.check_if_unused().is_err() =>
+				RoleRemovals::<T>::insert(&role_name, RoleRewriteCursorOf::default()),
+			None => Self::do_remove_role(role_name),
+			Some(new_role_name) if role_info.check_if_unused().is_err() =>
+				RoleRenames::<T>::insert(
+					&role_name,
+					RoleRename { new_role_name, cursor: RoleRewriteCursorOf::default() },
+				),
+			Some(new_role_name) => {
+				if let Some(spending) = RoleSpending::<T>::take(&role_name) {
+					RoleSpending::<T>::insert(&new_role_name, spending);
+				}
+				if let Some(usage) = RoleUsage::<T>::take(&role_name) {
+					RoleUsage::<T>::insert(&new_role_name, usage);
+				}
+				if let Some(budget) = RoleBudgets::<T>::take(&role_name) {
+					RoleBudgets::<T>::insert(&new_role_name, budget);
+				}
+				if let Some(metadata) = RolesMetadata::<T>::take(&role_name) {
+					RolesMetadata::<T>::insert(&new_role_name, metadata);
+				}
+				Roles::<T>::remove(&role_name);
+				RoleRenames::<T>::remove(&role_name);
+				RoleRenameSources::<T>::remove(&new_role_name);
+				RoleMembershipRules::<T>::mutate(|rules| rules.remove(&role_name));
+				Self::deposit_event(Event::<T>::RoleRenamed { role_name, new_role_name });
+			},
+		}
+		Ok(())
+	}
+
+	/// Removes a role from a set of roles and, if a new name is given, inserts the new name in its
+	/// place, moving the consumer reference over to the new name. Returns `None` if the role is not
+	/// in the set, `Some(true)` if it was replaced and `Some(false)` if it was dropped, including
+	/// when the new name was already in the set.
+	///
+	/// # Parameters
+	/// - `roles`: The set of roles to rewrite.
+	/// - `role_name`: The name of the role being removed or renamed.
+	/// - `new_role_name`: The new name of the role, or `None` if it is being removed.
+	fn rewrite_role_in_set<S: Get<u32>>(
+		roles: &mut BoundedBTreeSet<RoleNameOf<T>, S>,
+		role_name: &RoleNameOf<T>,
+		new_role_name: Option<&RoleNameOf<T>>,
+	) -> Result<Option<bool>, DispatchError> {
+		if !roles.remove(role_name) {
+			return Ok(None)
+		}
+		Self::dec_role_consumers(role_name)?;
+		match new_role_name {
+			// The set cannot be full as the role was just removed from it.
+			Some(new_role_name) if roles.try_insert(new_role_name.clone()).unwrap_or_default() => {
+				Self::inc_role_consumers(new_role_name)?;
+				Ok(Some(true))
+			},
+			_ => Ok(Some(false)),
+		}
+	}
+
+	/// Moves the validity period, scope and rate limit usage of an account's role assignment to
+	/// the role's new name.
+	///
+	/// # Parameters
+	/// - `who`: The account the role is assigned to.
+	/// - `role_name`: The name of the role being renamed.
+	/// - `new_role_name`: The new name of the role.
+	fn move_assignment(
+		who: &AccountIdOf<T>,
+		role_name: &RoleNameOf<T>,
+		new_role_name: &RoleNameOf<T>,
+	) {
+		if let Some(validity) = AssignmentValidities::<T>::take(who, role_name) {
+			if let Some(expires_at) = validity.expires_at {
+				ExpiringAssignments::<T>::mutate(expires_at, |expiring| {
+					expiring
+						.iter_mut()
+						.filter(|(account, name)| account == who && name == role_name)
+						.for_each(|(_, name)| *name = new_role_name.clone())
+				});
+			}
+			AssignmentValidities::<T>::insert(who, new_role_name, validity);
+		}
+		if let Some(scopes) = AssignmentScopes::<T>::take(who, role_name) {
+			AssignmentScopes::<T>::insert(who, new_role_name, scopes);
+		}
+		if let Some(usage) = AccountRoleUsage::<T>::take(role_name, who) {
+			AccountRoleUsage::<T>::insert(new_role_name, who, usage);
+		}
+	}
+
+	/// Rewrites the references to a role being force-removed or renamed in the storage of a
+	/// rewrite stage, visiting at most `limit` entries after the one stored at `last_key`. Returns
+	/// the number of visited entries and the raw key of the last one, which is `None` if the stage
+	/// is complete.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role being removed or renamed.
+	/// - `new_role_name`: The new name of the role, or `None` if it is being removed.
+	/// - `stage`: The rewrite stage, determining the storage to rewrite.
+	/// - `last_key`: The raw key of the last entry visited before, if any.
+	/// - `limit`: The maximum number of storage entries to visit.
+	fn rewrite_role_references(
+		role_name: &RoleNameOf<T>,
+		new_role_name: Option<&RoleNameOf<T>>,
+		stage: RoleRewriteStage,
+		last_key: Option<Vec<u8>>,
+		limit: u32,
+	) -> Result<(u32, Option<Vec<u8>>), DispatchError> {
+		match stage {
+			RoleRewriteStage::AccountRoles => Self::visit_entries(
+				last_key.map_or_else(AccountRoles::<T>::iter, AccountRoles::<T>::iter_from),
+				limit,
+				|who| AccountRoles::<T>::hashed_key_for(who),
+				|who, mut account_roles| {
+					match new_role_name {
+						None if account_roles.contains(role_name) =>
+							Self::do_unassign_role(&who, role_name)?,
+						Some(new_role_name) =>
+							if Self::rewrite_role_in_set(
+								&mut account_roles,
+								role_name,
+								Some(new_role_name),
+							)?
+							.is_some()
+							{
+								AccountRoles::<T>::insert(&who, account_roles);
+								RoleHolders::<T>::remove(role_name, &who);
+								RoleHolders::<T>::insert(new_role_name, &who, ());
+								Self::move_assignment(&who, role_name, new_role_name);
+							},
+						None => {},
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::CallRoles => Self::visit_entries(
+				last_key.map_or_else(CallRoles::<T>::iter, CallRoles::<T>::iter_from),
+				limit,
+				|call_metadata| CallRoles::<T>::hashed_key_for(call_metadata),
+				|call_metadata, mut call_roles| {
+					if Self::rewrite_role_in_set(&mut call_roles, role_name, new_role_name)?
+						.is_some()
+					{
+						CallRoles::<T>::insert(&call_metadata, call_roles);
+						let constraints = CallConstraints::<T>::take(&call_metadata, role_name);
+						let threshold = ApprovalThresholds::<T>::take(&call_metadata, role_name);
+						if let Some(new_role_name) = new_role_name {
+							if let Some(constraints) = constraints {
+								CallConstraints::<T>::insert(
+									&call_metadata,
+									new_role_name,
+									constraints,
+								);
+							}
+							if let Some(threshold) = threshold {
+								ApprovalThresholds::<T>::insert(
+									&call_metadata,
+									new_role_name,
+									threshold,
+								);
+							}
+						}
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::CallDeniedRoles => Self::visit_entries(
+				last_key.map_or_else(CallDeniedRoles::<T>::iter, CallDeniedRoles::<T>::iter_from),
+				limit,
+				|call_metadata| CallDeniedRoles::<T>::hashed_key_for(call_metadata),
+				|call_metadata, mut denied_roles| {
+					if Self::rewrite_role_in_set(&mut denied_roles, role_name, new_role_name)?
+						.is_some()
+					{
+						CallDeniedRoles::<T>::insert(&call_metadata, denied_roles);
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::PalletRoles => Self::visit_entries(
+				last_key.map_or_else(PalletRoles::<T>::iter, PalletRoles::<T>::iter_from),
+				limit,
+				|pallet_index| PalletRoles::<T>::hashed_key_for(pallet_index),
+				|pallet_index, mut pallet_roles| {
+					if Self::rewrite_role_in_set(&mut pallet_roles, role_name, new_role_name)?
+						.is_some()
+					{
+						PalletRoles::<T>::insert(pallet_index, pallet_roles);
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::GroupRoles => Self::visit_entries(
+				last_key.map_or_else(GroupRoles::<T>::iter, GroupRoles::<T>::iter_from),
+				limit,
+				|group_name| GroupRoles::<T>::hashed_key_for(group_name),
+				|group_name, mut group_roles| {
+					if Self::rewrite_role_in_set(&mut group_roles, role_name, new_role_name)?
+						.is_some()
+					{
+						if group_roles.is_empty() {
+							GroupRoles::<T>::remove(&group_name);
+						} else {
+							GroupRoles::<T>::insert(&group_name, group_roles);
+						}
+						RoleGroups::<T>::remove(role_name, &group_name);
+						if let Some(new_role_name) = new_role_name {
+							RoleGroups::<T>::insert(new_role_name, &group_name, ());
+						}
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::Invitations => Self::visit_entries(
+				last_key.map_or_else(Invitations::<T>::iter, Invitations::<T>::iter_from).map(
+					|(who, invited_role_name, expires_at)| ((who, invited_role_name), expires_at),
+				),
+				limit,
+				|(who, invited_role_name)| Invitations::<T>::hashed_key_for(who, invited_role_name),
+				|(who, invited_role_name), expires_at| {
+					if &invited_role_name == role_name {
+						Invitations::<T>::remove(&who, role_name);
+						Self::dec_role_consumers(role_name)?;
+						if let Some(new_role_name) = new_role_name {
+							if !Invitations::<T>::contains_key(&who, new_role_name) {
+								Invitations::<T>::insert(&who, new_role_name, expires_at);
+								Self::inc_role_consumers(new_role_name)?;
+							}
+						}
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::RoleParents => Self::visit_entries(
+				last_key.map_or_else(RoleParents::<T>::iter, RoleParents::<T>::iter_from),
+				limit,
+				|name| RoleParents::<T>::hashed_key_for(name),
+				|name, mut parents| {
+					if &name == role_name {
+						RoleParents::<T>::remove(&name);
+						for parent_name in parents {
+							Self::dec_role_consumers(role_name)?;
+							Self::remove_child_role(&parent_name, role_name);
+							let inherited = match new_role_name {
+								Some(new_role_name) =>
+									RoleParents::<T>::try_mutate(new_role_name, |new_parents| {
+										new_parents
+											.get_or_insert(ParentRolesListOf::<T>::default())
+											.try_insert(parent_name.clone())
+											.map_err(|_| Error::<T>::TooManyParentRoles)
+									})?,
+								None => false,
+							};
+							match (inherited, new_role_name) {
+								(true, Some(new_role_name)) => {
+									Self::insert_child_role(&parent_name, new_role_name)?;
+									Self::inc_role_consumers(new_role_name)?
+								},
+								_ => Self::dec_role_consumers(&parent_name)?,
+							}
+						}
+					} else if let Some(replaced) =
+						Self::rewrite_role_in_set(&mut parents, role_name, new_role_name)?
+					{
+						if parents.is_empty() {
+							RoleParents::<T>::remove(&name);
+						} else {
+							RoleParents::<T>::insert(&name, parents);
+						}
+						Self::remove_child_role(role_name, &name);
+						match (replaced, new_role_name) {
+							(true, Some(new_role_name)) =>
+								Self::insert_child_role(new_role_name, &name)?,
+							_ => Self::dec_role_consumers(&name)?,
+						}
+					}
+					Ok(())
+				},
+			),
+			RoleRewriteStage::RoleAdmins => Self::visit_entries(
+				last_key.map_or_else(RoleAdmins::<T>::iter, RoleAdmins::<T>::iter_from),
+				limit,
+				|name| RoleAdmins::<T>::hashed_key_for(name),
+				|name, admin_role_name| {
+					if &name != role_name && &admin_role_name != role_name {
+						return Ok(())
+					}
+					RoleAdmins::<T>::remove(&name);
+					Self::dec_role_consumers(&name)?;
+					Self::dec_role_consumers(&admin_role_name)?;
+					if let Some(new_role_name) = new_role_name {
+						let renamed = |name: RoleNameOf<T>| {
+							if &name == role_name {
+								new_role_name.clone()
+							} else {
+								name
+							}
+						};
+						let (name, admin_role_name) = (renamed(name), renamed(admin_role_name));
+						if !RoleAdmins::<T>::contains_key(&name) {
+							Self::inc_role_consumers(&name)?;
+							Self::inc_role_consumers(&admin_role_name)?;
+							RoleAdmins::<T>::insert(name, admin_role_name);
+						}
+					}
+					Ok(())
+				},
+			),
+			// Visited proposals are moved out of the role's prefix, so the remaining ones are
+			// always visited from the start of the prefix.
+			RoleRewriteStage::Proposals => Self::visit_entries(
+				Proposals::<T>::iter_prefix(role_name)
+					.take(limit as usize)
+					.collect::<Vec<_>>()
+					.into_iter(),
+				limit,
+				|call_hash| Proposals::<T>::hashed_key_for(role_name, call_hash),
+				|call_hash, proposal| {
+					Proposals::<T>::remove(role_name, call_hash);
+					match new_role_name {
+						Some(new_role_name)
+							if !Proposals::<T>::contains_key(new_role_name, call_hash) =>
+							Proposals::<T>::insert(new_role_name, call_hash, proposal),
+						_ => {
+							T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+							Self::deposit_event(Event::<T>::ProposalDropped {
+								role_name: role_name.clone(),
+								call_hash,
+							});
+						},
+					}
+					Ok(())
+				},
+			),
+			// Like proposals, visited usage windows are moved out of the role's prefix.
+			RoleRewriteStage::AccountRoleUsage => Self::visit_entries(
+				AccountRoleUsage::<T>::iter_prefix(role_name)
+					.take(limit as usize)
+					.collect::<Vec<_>>()
+					.into_iter(),
+				limit,
+				|who| AccountRoleUsage::<T>::hashed_key_for(role_name, who),
+				|who, usage| {
+					AccountRoleUsage::<T>::remove(role_name, &who);
+					if let Some(new_role_name) = new_role_name {
+						if !AccountRoleUsage::<T>::contains_key(new_role_name, &who) {
+							AccountRoleUsage::<T>::insert(new_role_name, &who, usage);
+						}
+					}
+					Ok(())
+				},
+			),
+		}
+	}
+
+	/// Visits at most `limit` storage entries. Returns the number of visited entries and the raw
+	/// key of the last one, unless the entries ran out before reaching the limit.
+	///
+	/// # Parameters
+	/// - `entries`: The storage entries to visit, in iteration order.
+	/// - `limit`: The maximum number of entries to visit.
+	/// - `raw_key`: Returns the raw storage key of an entry.
+	/// - `visit`: Called for every visited entry.
+	fn visit_entries<K, V>(
+		entries: impl Iterator<Item = (K, V)>,
+		limit: u32,
+		raw_key: impl Fn(&K) -> Vec<u8>,
+		mut visit: impl FnMut(K, V) -> DispatchResult,
+	) -> Result<(u32, Option<Vec<u8>>), DispatchError> {
+		let mut visited = 0u32;
+		let mut last_key = None;
+		for (key, value) in entries.take(limit as usize) {
+			last_key = Some(raw_key(&key));
+			visit(key, value)?;
+			visited.saturating_inc();
+		}
+		Ok((visited, if visited < limit { None } else { last_key }))
+	}
+
+	/// Assigns a role to an account and increases the role's consumers counter.
+	///
+	/// # Parameters
+	/// - `who`: The account to assign the role to.
+	/// - `role_name`: The name of the role to assign.
+	pub fn do_assign_role(who: &AccountIdOf<T>, role_name: &RoleNameOf<T>) -> DispatchResult {
+		Self::check_role_existance_and_version(role_name)?;
+		Self::ensure_no_exclusive_roles(who, role_name)?;
+
+		AccountRoles::<T>::mutate(who, |account_roles| {
+			let account_roles = account_roles.get_or_insert(AccountRolesListOf::<T>::default());
+			ensure!(!account_roles.contains(role_name), Error::<T>::RoleAlreadyAssigned);
+			account_roles
+				.try_insert(role_name.clone())
+				.map_err(|_| Error::<T>::TooManyRolesPerAccount)
+		})?;
+		RoleHolders::<T>::insert(role_name, who, ());
+		Self::inc_role_consumers(role_name)?;
+		Self::deposit_event(Event::<T>::AccountAssignedToRole {
+			role_name: role_name.clone(),
+			who: who.clone(),
+		});
+		Ok(())
+	}
+
+	/// Makes a role inherit the permissions of a parent role and increases the consumers counters
+	/// of both roles.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the inheriting role.
+	/// - `parent_role_name`: The name of the parent role.
+	fn do_add_parent_role(
+		role_name: &RoleNameOf<T>,
+		parent_role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		Self::check_role_existance_and_version(role_name)?;
+		Self::check_role_existance_and_version(parent_role_name)?;
+		Self::ensure_can_inherit(role_name, parent_role_name)?;
+		Self::ensure_inheritance_keeps_exclusivity(role_name, parent_role_name)?;
+
+		RoleParents::<T>::mutate(role_name, |parents| {
+			let parents = parents.get_or_insert(ParentRolesListOf::<T>::default());
+			ensure!(!parents.contains(parent_role_name), Error::<T>::ParentRoleAlreadyAdded);
+			parents
+				.try_insert(parent_role_name.clone())
+				.map_err(|_| Error::<T>::TooManyParentRoles)
+		})?;
+		Self::insert_child_role(parent_role_name, role_name)?;
+		Self::inc_role_consumers(role_name)?;
+		Self::inc_role_consumers(parent_role_name)?;
+		Self::deposit_event(Event::<T>::ParentRoleAdded {
+			role_name: role_name.clone(),
+			parent_role_name: parent_role_name.clone(),
+		});
+		Ok(())
+	}
+
+	/// Records a role as inheriting directly from a parent role in the index of child roles.
+	///
+	/// # Parameters
+	/// - `parent_role_name`: The name of the parent role.
+	/// - `role_name`: The name of the inheriting role.
+	pub(crate) fn insert_child_role(
+		parent_role_name: &RoleNameOf<T>,
+		role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		RoleChildren::<T>::try_mutate(parent_role_name, |children| {
+			children
+				.get_or_insert(ChildRolesListOf::<T>::default())
+				.try_insert(role_name.clone())
+				.map_err(|_| Error::<T>::TooManyChildRoles)
+		})?;
+		Ok(())
+	}
+
+	/// Removes a role from the index of child roles of a parent role it no longer inherits from.
+	///
+	/// # Parameters
+	/// - `parent_role_name`: The name of the parent role.
+	/// - `role_name`: The name of the role which no longer inherits from the parent role.
+	fn remove_child_role(parent_role_name: &RoleNameOf<T>, role_name: &RoleNameOf<T>) {
+		RoleChildren::<T>::mutate_exists(parent_role_name, |children| {
+			if let Some(roles) = children.as_mut() {
+				roles.remove(role_name);
+				if roles.is_empty() {
+					*children = None;
+				}
+			}
+		});
+	}
+
+	/// Assigns a role to a group and increases the role's consumers counter.
+	///
+	/// # Parameters
+	/// - `group_name`: The name of the group to assign the role to.
+	/// - `role_name`: The name of the role to assign.
+	fn do_assign_role_to_group(
+		group_name: &GroupNameOf<T>,
+		role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		Self::check_role_existance_and_version(role_name)?;
+		let members = Self::group_members(group_name).ok_or(Error::<T>::GroupDoesNotExist)?;
+		let group_roles = Self::group_roles(group_name).unwrap_or_default();
+		ensure!(!group_roles.contains(role_name), Error::<T>::RoleAlreadyAssignedToGroup);
+
+		let group_roles: BTreeSet<RoleNameOf<T>> =
+			group_roles.iter().flat_map(Self::role_with_ancestors).collect();
+		Self::ensure_not_exclusive_with(&group_roles, role_name)?;
+		for member in members.iter() {
+			Self::ensure_no_exclusive_roles(member, role_name)?;
+		}
+		GroupRoles::<T>::try_mutate(group_name, |group_roles| {
+			group_roles
+				.get_or_insert(GroupRolesListOf::<T>::default())
+				.try_insert(role_name.clone())
+				.map_err(|_| Error::<T>::TooManyRolesPerGroup)
+		})?;
+		RoleGroups::<T>::insert(role_name, group_name, ());
+		Self::inc_role_consumers(role_name)?;
+		Self::deposit_event(Event::<T>::GroupAssignedToRole {
+			role_name: role_name.clone(),
+			group_name: group_name.clone(),
+		});
+		Ok(())
+	}
+
+	/// Makes two roles mutually exclusive by recording the exclusion for both of them.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the first role.
+	/// - `other_role_name`: The name of the second role.
+	pub(crate) fn insert_exclusive_roles(
+		role_name: &RoleNameOf<T>,
+		other_role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		ensure!(role_name != other_role_name, Error::<T>::RoleExclusiveWithItself);
+		ensure!(
+			!Self::is_held_by_rule(role_name)? && !Self::is_held_by_rule(other_role_name)?,
+			Error::<T>::RuleBasedExclusiveRole
+		);
+		for (name, other_name) in [(role_name, other_role_name), (other_role_name, role_name)] {
+			ExclusiveRoles::<T>::mutate(name, |exclusive_roles| {
+				let exclusive_roles =
+					exclusive_roles.get_or_insert(ExclusiveRolesListOf::<T>::default());
+				ensure!(!exclusive_roles.contains(other_name), Error::<T>::RolesAlreadyExclusive);
+				exclusive_roles
+					.try_insert(other_name.clone())
+					.map_err(|_| Error::<T>::TooManyExclusiveRoles)
+			})?;
+		}
+		Ok(())
+	}
+
+	/// Returns the roles assigned to an account, directly or through its groups, and the roles
+	/// whose membership rule it satisfies, together with all of their ancestors.
+	fn account_roles_with_ancestors(who: &AccountIdOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		let mut assigned_roles = Self::account_implicit_roles(who);
+		assigned_roles.extend(Self::account_roles(who).unwrap_or_default());
+		assigned_roles.iter().flat_map(Self::role_with_ancestors).collect()
+	}
+
+	/// Ensures that making a role inherit from a parent role would not make any account hold two
+	/// mutually exclusive roles, through the role itself or any of its descendants.
+	///
+	/// Accounts are only visited if the parent role or one of its ancestors has exclusive roles
+	/// at all, and at most `InheritanceChecksLimit` accounts and groups are visited. Accounts
+	/// satisfying a membership rule cannot be listed, so the inheritance is rejected in that case
+	/// if the role or one of its descendants has a membership rule.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the inheriting role.
+	/// - `parent_role_name`: The name of the role to be inherited from.
+	fn ensure_inheritance_keeps_exclusivity(
+		role_name: &RoleNameOf<T>,
+		parent_role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		if !Self::has_exclusive_roles(parent_role_name) {
+			return Ok(())
+		}
+		Self::ensure_not_exclusive_with(&Self::role_with_ancestors(role_name), parent_role_name)?;
+		ensure!(!Self::is_held_by_rule(role_name)?, Error::<T>::RuleBasedExclusiveRole);
+
+		let (inheriting_roles, _) = Self::role_with_descendants(role_name)?;
+		let mut checks = 0u32;
+		let mut count_check = || {
+			checks.saturating_inc();
+			ensure!(checks <= T::InheritanceChecksLimit::get(), Error::<T>::TooManyRoleHolders);
+			Ok::<_, Error<T>>(())
+		};
+		for inheriting_role in inheriting_roles.iter() {
+			let mut holders: Vec<AccountIdOf<T>> = Vec::new();
+			for who in RoleHolders::<T>::iter_key_prefix(inheriting_role) {
+				count_check()?;
+				holders.push(who);
+			}
+			for group_name in RoleGroups::<T>::iter_key_prefix(inheriting_role) {
+				count_check()?;
+				for who in Self::group_members(group_name).unwrap_or_default() {
+					count_check()?;
+					holders.push(who);
+				}
+			}
+			for who in holders {
+				Self::ensure_not_exclusive_with(
+					&Self::account_roles_with_ancestors(&who),
+					parent_role_name,
+				)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Checks whether a role or any of its ancestors is mutually exclusive with other roles.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to check.
+	fn has_exclusive_roles(role_name: &RoleNameOf<T>) -> bool {
+		Self::role_with_ancestors(role_name)
+			.iter()
+			.any(|name| !Self::exclusive_roles(name).unwrap_or_default().is_empty())
+	}
+
+	/// Checks whether accounts can hold a role through a membership rule, either set on the role
+	/// itself or on one of its descendants. Fails if the descendants cannot be resolved.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to check.
+	fn is_held_by_rule(role_name: &RoleNameOf<T>) -> Result<bool, DispatchError> {
+		let rules = Self::role_membership_rules();
+		if rules.is_empty() {
+			return Ok(false)
+		}
+		let (inheriting_roles, _) = Self::role_with_descendants(role_name)?;
+		Ok(inheriting_roles.iter().any(|name| rules.contains_key(name)))
+	}
+
+	/// Ensures that assigning a role to an account would not make it hold two mutually exclusive
+	/// roles, taking into account the ancestors of the role and of the roles already assigned.
+	///
+	/// # Parameters
+	/// - `who`: The account to assign the role to.
+	/// - `role_name`: The name of the role to assign.
+	pub fn ensure_no_exclusive_roles(
+		who: &AccountIdOf<T>,
+		role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		Self::ensure_not_exclusive_with(&Self::account_roles_with_ancestors(who), role_name)
+	}
+
+	/// Ensures that neither a role nor any of its ancestors is mutually exclusive with any of
+	/// the given roles.
+	///
+	/// # Parameters
+	/// - `held_roles`: The roles to check against, including their ancestors.
+	/// - `role_name`: The name of the role to check.
+	fn ensure_not_exclusive_with(
+		held_roles: &BTreeSet<RoleNameOf<T>>,
+		role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		let conflicting = Self::role_with_ancestors(role_name).iter().any(|name| {
+			Self::exclusive_roles(name)
+				.unwrap_or_default()
+				.iter()
+				.any(|other_name| held_roles.contains(other_name))
+		});
+		ensure!(!conflicting, Error::<T>::ExclusiveRoleHeld);
+		Ok(())
+	}
+
+	/// Returns the pairs of mutually exclusive roles held by an account, either directly or
+	/// through roles inheriting from them.
+	///
+	/// # Parameters
+	/// - `who`: The account to check.
+	pub fn account_exclusive_role_violations(
+		who: &AccountIdOf<T>,
+	) -> Vec<(RoleNameOf<T>, RoleNameOf<T>)> {
+		let held_roles = Self::account_roles_with_ancestors(who);
+		held_roles
+			.iter()
+			.flat_map(|name| {
+				Self::exclusive_roles(name)
+					.unwrap_or_default()
+					.into_iter()
+					.filter(|other_name| name < other_name && held_roles.contains(other_name))
+					.map(|other_name| (name.clone(), other_name))
+					.collect::<Vec<_>>()
+			})
+			.collect()
+	}
+
+	/// Returns every account holding mutually exclusive roles together with the offending pairs
+	/// of roles. Iterates over all role assignments and group memberships, so it is meant to be
+	/// used off-chain through the `RbacApi` runtime API, e.g. to audit existing assignments after
+	/// new exclusions are added.
+	pub fn exclusive_role_violations() -> Vec<(AccountIdOf<T>, RoleNameOf<T>, RoleNameOf<T>)> {
+		AccountRoles::<T>::iter_keys()
+			.chain(AccountGroups::<T>::iter_keys())
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.flat_map(|who| {
+				Self::account_exclusive_role_violations(&who)
+					.into_iter()
+					.map(move |(name, other_name)| (who.clone(), name, other_name))
+			})
+			.collect()
+	}
+
+	/// Unassigns a role from an account, drops the assignment's validity period and decreases the
+	/// role's consumers counter.
+	///
+	/// # Parameters
+	/// - `who`: The account to unassign the role from.
+	/// - `role_name`: The name of the role to unassign.
+	pub fn do_unassign_role(who: &AccountIdOf<T>, role_name: &RoleNameOf<T>) -> DispatchResult {
+		Self::take_account_role(who, role_name)?;
+		Self::dec_role_consumers(role_name)?;
+		Self::deposit_event(Event::<T>::AccountUnassignedFromRole {
+			role_name: role_name.clone(),
+			who: who.clone(),
+		});
+		Ok(())
+	}
+
+	/// Removes a role from account's roles set along with the assignment's validity period.
+	fn take_account_role(who: &AccountIdOf<T>, role_name: &RoleNameOf<T>) -> DispatchResult {
+		AccountRoles::<T>::mutate(who, |account_roles| {
+			if let Some(account_roles) = account_roles.as_mut() {
+				ensure!(account_roles.remove(role_name), Error::<T>::MissingRole);
+				Ok(())
+			} else {
+				Err(Error::<T>::MissingRole)
+			}
+		})?;
+		RoleHolders::<T>::remove(role_name, who);
+		AssignmentValidities::<T>::remove(who, role_name);
+		AssignmentScopes::<T>::remove(who, role_name);
+		AccountRoleUsage::<T>::remove(role_name, who);
+		Ok(())
+	}
+
+	/// Checks whether an account's role permits a call with respect to the resource the call
+	/// operates on. Implicitly held roles and unscoped assignments permit any call, while
+	/// scoped assignments only permit calls operating on one of their resources, which cannot be
+	/// checked when the call itself is not provided.
+	///
+	/// # Parameters
+	/// - `who`: The account holding the role.
+	/// - `role_name`: The name of the held role.
+	/// - `call`: The call whose resource is checked against the assignment's scope, if available.
+	pub fn is_in_scope(
+		who: &AccountIdOf<T>,
+		role_name: &RoleNameOf<T>,
+		call: Option<&T::ExtendedRuntimeCall>,
+	) -> bool {
+		if Self::account_implicit_roles(who).contains(role_name) {
+			return true
+		}
+		Self::assignment_scopes(who, role_name).map_or(true, |scopes| {
+			call.and_then(|call| call.resource())
+				.map_or(false, |resource| scopes.contains(&resource))
+		})
+	}
+
+	/// Limits an existing role assignment to a period of blocks and schedules its removal at the
+	/// block it expires.
+	///
+	/// # Parameters
+	/// - `who`: The account the role is assigned to.
+	/// - `role_name`: The name of the assigned role.
+	/// - `validity`: The period of blocks during which the assignment is in effect.
+	pub fn set_assignment_validity(
+		who: &AccountIdOf<T>,
+		role_name: &RoleNameOf<T>,
+		validity: AssignmentValidityOf<T>,
+	) -> DispatchResult {
+		let now = System::<T>::block_number();
+		ensure!(!validity.is_expired_at(&now), Error::<T>::InvalidAssignmentValidity);
+		if let (Some(not_before), Some(expires_at)) = (&validity.not_before, &validity.expires_at) {
+			ensure!(not_before < expires_at, Error::<T>::InvalidAssignmentValidity);
+		}
+
+		if let Some(expires_at) = validity.expires_at {
+			ExpiringAssignments::<T>::try_mutate(expires_at, |expiring| {
+				expiring
+					.try_push((who.clone(), role_name.clone()))
+					.map_err(|_| Error::<T>::TooManyExpiriesPerBlock)
+			})?;
+		}
+		AssignmentValidities::<T>::insert(who, role_name, validity.clone());
+		Self::deposit_event(Event::<T>::AssignmentValiditySet {
+			role_name: role_name.clone(),
+			who: who.clone(),
+			validity,
+		});
+		Ok(())
+	}
+
+	/// Checks whether a role assignment is in effect at the current block. Assignments without a
+	/// validity period are always in effect.
+	///
+	/// # Parameters
+	/// - `who`: The account the role is assigned to.
+	/// - `role_name`: The name of the assigned role.
+	pub fn is_assignment_active(who: &AccountIdOf<T>, role_name: &RoleNameOf<T>) -> bool {
+		Self::assignment_validity(who, role_name)
+			.map_or(true, |validity| validity.is_active_at(&System::<T>::block_number()))
+	}
+
+	/// Removes a role assignment scheduled to expire at `block`, unless it was unassigned or
+	/// reassigned with a different validity period in the meantime.
+	///
+	/// # Parameters
+	/// - `block`: The block at which the assignment was scheduled to expire.
+	/// - `who`: The account the role is assigned to.
+	/// - `role_name`: The name of the assigned role.
+	pub(crate) fn expire_assignment(
+		block: BlockNumberFor<T>,
+		who: &AccountIdOf<T>,
+		role_name: &RoleNameOf<T>,
+	) {
+		let is_due = Self::assignment_validity(who, role_name)
+			.map_or(false, |validity| validity.expires_at == Some(block));
+		if is_due && Self::take_account_role(who, role_name).is_ok() {
+			let _ = Self::dec_role_consumers(role_name);
+			Self::deposit_event(Event::<T>::AssignmentExpired {
+				role_name: role_name.clone(),
+				who: who.clone(),
+			});
+		}
+	}
+
+	/// Walks the blocks since the last cleanup up to `now` and removes the role assignments that
+	/// expired at them, as long as `remaining_weight` allows. Returns the consumed weight.
+	///
+	/// # Parameters
+	/// - `now`: The current block number.
+	/// - `remaining_weight`: The weight available for the cleanup.
+	pub(crate) fn remove_expired_assignments(
+		now: BlockNumberFor<T>,
+		remaining_weight: Weight,
+	) -> Weight {
+		let per_block = T::DbWeight::get().reads_writes(1, 1);
+		let per_assignment = T::WeightInfo::expire_assignment();
+		let mut consumed = T::DbWeight::get().reads_writes(1, 1);
+		if remaining_weight.any_lt(consumed) {
+			return Weight::zero()
+		}
+
+		let mut cursor = Self::last_expiry_cleanup();
+		while cursor < now {
+			if remaining_weight.any_lt(consumed.saturating_add(per_block)) {
+				break
+			}
+			consumed.saturating_accrue(per_block);
+			let block = cursor.saturating_add(One::one());
+			let mut expiring = ExpiringAssignments::<T>::take(block).into_inner();
+			while let Some((who, role_name)) = expiring.pop() {
+				if remaining_weight.any_lt(consumed.saturating_add(per_assignment)) {
+					expiring.push((who, role_name));
+					break
+				}
+				consumed.saturating_accrue(per_assignment);
+				Self::expire_assignment(block, &who, &role_name);
+			}
+			if !expiring.is_empty() {
+				ExpiringAssignments::<T>::insert(
+					block,
+					ExpiringAssignmentsOf::<T>::truncate_from(expiring),
+				);
+				break
+			}
+			cursor = block;
+		}
+		LastExpiryCleanup::<T>::put(cursor);
+		consumed
+	}
+
+	/// Counts a call dispatched by an account using a role against the role's rate limit. Fails
+	/// with `RateLimitExceeded` without counting the call if the limit has already been reached.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role used to dispatch the call.
+	/// - `who`: The account dispatching the call.
+	pub fn use_role(role_name: &RoleNameOf<T>, who: &AccountIdOf<T>) -> DispatchResult {
+		let rate_limit = match Self::roles(role_name).and_then(|info| info.rate_limit()) {
+			Some(rate_limit) => rate_limit,
+			None => return Ok(()),
+		};
+		let window_start = Self::rate_limit_window_start(&rate_limit);
+		let count_call = |usage: &mut Option<UsageWindowOf<T>>| -> DispatchResult {
+			match usage {
+				Some(usage) if usage.window_start == window_start => {
+					ensure!(usage.calls < rate_limit.max_calls, Error::<T>::RateLimitExceeded);
+					usage.calls = usage.calls.saturating_add(1);
+				},
+				_ => *usage = Some(UsageWindow { window_start, calls: 1 }),
+			}
+			Ok(())
+		};
+		match rate_limit.scope {
+			RateLimitScope::PerAccount =>
+				AccountRoleUsage::<T>::try_mutate(role_name, who, count_call),
+			RateLimitScope::Global => RoleUsage::<T>::try_mutate(role_name, count_call),
+		}
+	}
+
+	/// Checks whether an account can dispatch a call using a role without exceeding the role's
+	/// rate limit, without counting the call.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to be used.
+	/// - `who`: The account dispatching the call.
+	pub fn can_use_role(role_name: &RoleNameOf<T>, who: &AccountIdOf<T>) -> bool {
+		let rate_limit = match Self::roles(role_name).and_then(|info| info.rate_limit()) {
+			Some(rate_limit) => rate_limit,
+			None => return true,
+		};
+		let window_start = Self::rate_limit_window_start(&rate_limit);
+		let usage = match rate_limit.scope {
+			RateLimitScope::PerAccount => Self::account_role_usage(role_name, who),
+			RateLimitScope::Global => Self::role_usage(role_name),
+		};
+		usage.map_or(true, |usage| {
+			usage.window_start != window_start || usage.calls < rate_limit.max_calls
+		})
+	}
+
+	/// Returns the first block of the current period of a rate limit. Periods are counted in
+	/// blocks and aligned to multiples of their length.
+	///
+	/// # Parameters
+	/// - `rate_limit`: The rate limit to compute the period of.
+	fn rate_limit_window_start(rate_limit: &RateLimit) -> BlockNumberFor<T> {
+		let now = System::<T>::block_number();
+		let period: BlockNumberFor<T> = rate_limit.period.into();
+		now.saturating_sub(now % period)
+	}
+
+	/// Counts funds moved by a call dispatched using a role against the role's spending budget.
+	/// Fails with `BudgetExceeded` without counting the funds if they do not fit into the budget
+	/// left for the current period, depositing `RoleBudgetExhausted`. Periods are counted in
+	/// blocks and aligned to multiples of their length.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role used to dispatch the call.
+	/// - `amount`: The amount of funds moved by the call.
+	pub fn spend_budget(role_name: &RoleNameOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		// The budget of a role being renamed stays with its name until the role is renamed.
+		let budget = sp_std::iter::once(role_name.clone())
+			.chain(Self::renamed_role_alias(role_name))
+			.find_map(|name| Self::role_budget(&name).map(|budget| (name, budget)));
+		let (role_name, budget) = match budget {
+			Some(budget) => budget,
+			None => return Ok(()),
+		};
+		let now = System::<T>::block_number();
+		let period: BlockNumberFor<T> = budget.period.into();
+		let window_start = now.saturating_sub(now % period);
+		let spent = RoleSpending::<T>::get(&role_name)
+			.filter(|spending| spending.window_start == window_start)
+			.map_or_else(Zero::zero, |spending| spending.spent);
+		let spent = match spent.checked_add(&amount) {
+			Some(spent) if spent <= budget.limit => spent,
+			_ => {
+				Self::deposit_event(Event::<T>::RoleBudgetExhausted {
+					role_name: role_name.clone(),
+					window_start,
+				});
+				return Err(Error::<T>::BudgetExceeded.into())
+			},
+		};
+
+		RoleSpending::<T>::insert(role_name, SpendingWindow { window_start, spent });
+		Ok(())
+	}
+
+	/// Returns the role itself together with all of its ancestors.
+	///
+	/// Parent links are followed breadth-first, at most `MaxRoleHierarchyDepth` levels up.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to resolve the ancestors for.
+	pub fn role_with_ancestors(role_name: &RoleNameOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		Self::resolve_ancestors(role_name, |_| true)
+	}
+
+	/// Returns the role itself together with the ancestors it inherits permissions from. The
+	/// permissions of a suspended ancestor, including the ones it inherits itself, are not
+	/// inherited while it is suspended.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to resolve the granting ancestors for.
+	pub fn role_with_granting_ancestors(role_name: &RoleNameOf<T>) -> BTreeSet<RoleNameOf<T>> {
+		Self::resolve_ancestors(role_name, |parent| {
+			Self::roles(parent).map_or(false, |role_info| !role_info.is_suspended())
+		})
+	}
+
+	/// Returns the role itself together with the ancestors reachable through parent links to
+	/// roles accepted by the given filter. For a role being renamed, the other name of the role
+	/// and its ancestors are included as well, as the references to the role are split between
+	/// both names until the renaming is complete.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to resolve the ancestors for.
+	/// - `follow`: Decides whether a parent role, and the links above it, are followed.
+	fn resolve_ancestors(
+		role_name: &RoleNameOf<T>,
+		follow: impl Fn(&RoleNameOf<T>) -> bool,
+	) -> BTreeSet<RoleNameOf<T>> {
+		let mut current_level = vec![role_name.clone()];
+		current_level.extend(Self::renamed_role_alias(role_name));
+		let mut resolved: BTreeSet<RoleNameOf<T>> = current_level.iter().cloned().collect();
+		for _ in 0..T::MaxRoleHierarchyDepth::get() {
+			let next_level: Vec<RoleNameOf<T>> = current_level
+				.iter()
+				.flat_map(|name| Self::role_parents(name).unwrap_or_default().into_iter())
+				.filter(|parent| follow(parent) && resolved.insert(parent.clone()))
+				.collect();
+			if next_level.is_empty() {
+				break
+			}
+			current_level = next_level;
+		}
+		resolved
+	}
+
+	/// Returns the role itself together with all of its descendants, i.e. the roles inheriting its
+	/// permissions, and the length of the longest chain of parent links from a descendant to the
+	/// role.
+	///
+	/// Child roles are followed breadth-first through `RoleChildren`, at most
+	/// `MaxRoleHierarchyDepth` levels down. Fails with `TooManyDescendantRoles` if the role has
+	/// more than `MaxRoleHierarchyDepth` times `ChildRolesLimit` descendants, which bounds the
+	/// number of roles read.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to resolve the descendants for.
+	pub fn role_with_descendants(
+		role_name: &RoleNameOf<T>,
+	) -> Result<(BTreeSet<RoleNameOf<T>>, u32), DispatchError> {
+		let max_descendants =
+			T::MaxRoleHierarchyDepth::get().saturating_mul(T::ChildRolesLimit::get());
+		let mut resolved = BTreeSet::new();
+		resolved.insert(role_name.clone());
+		let mut current_level = BTreeSet::new();
+		current_level.insert(role_name.clone());
+		let mut depth = 0u32;
+		for _ in 0..T::MaxRoleHierarchyDepth::get() {
+			let next_level: BTreeSet<RoleNameOf<T>> = current_level
+				.iter()
+				.flat_map(|name| Self::role_children(name).unwrap_or_default().into_iter())
+				.collect();
+			if next_level.is_empty() {
+				break
+			}
+			depth.saturating_inc();
+			resolved.extend(next_level.iter().cloned());
+			ensure!(
+				resolved.len() as u32 <= max_descendants.saturating_add(1),
+				Error::<T>::TooManyDescendantRoles
+			);
+			current_level = next_level;
+		}
+		Ok((resolved, depth))
+	}
+
+	/// Checks whether a call is denied to an account, either directly or through any of the roles
+	/// the account holds, including roles denied to one of their ancestors.
+	///
+	/// # Parameters
+	/// - `who`: The account making the call.
+	/// - `call_metadata`: The metadata of the call to look for.
+	pub fn is_call_denied(who: &AccountIdOf<T>, call_metadata: &T::CallMetadata) -> bool {
+		if AccountDenials::<T>::contains_key(who, call_metadata) {
+			return true
+		}
+		let denied_roles = Self::call_denied_roles(call_metadata).unwrap_or_default();
+		!denied_roles.is_empty() &&
+			Self::held_roles(who)
+				.iter()
+				.filter(|role_name| {
+					Self::roles(role_name).map_or(false, |role_info| {
+						role_info.check_version(System::<T>::runtime_version()).is_ok()
+					})
+				})
+				.any(|role_name| {
+					Self::role_with_ancestors(role_name)
+						.iter()
+						.any(|name| denied_roles.contains(name))
+				})
+	}
+
+	/// Records the consent of the account a role dispatches calls as.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role dispatching calls as the account.
+	/// - `who`: The account giving or revoking its consent.
+	/// - `consent`: The consent to record, either `Given` or `Revoked`.
+	fn set_impersonation_consent(
+		role_name: &RoleNameOf<T>,
+		who: &AccountIdOf<T>,
+		consent: ImpersonationConsent,
+	) -> DispatchResult {
+		Roles::<T>::try_mutate(role_name, |info| {
+			let info = info.as_mut().ok_or(Error::<T>::RoleDoesNotExist)?;
+			ensure!(info.impersonated_account() == Some(who), Error::<T>::NotImpersonatedAccount);
+			ensure!(
+				info.consent() != consent,
+				match consent {
+					ImpersonationConsent::Given => Error::<T>::ConsentAlreadyGiven,
+					_ => Error::<T>::ConsentAlreadyRevoked,
+				}
+			);
+			info.set_consent(consent);
+			Ok(())
+		})
+	}
+
+	/// Ensures that an account can execute a call with a role, i.e. the account holds the role
+	/// directly or implicitly, the assignment's scope covers the call's resource, the impersonated
+	/// account, if any, consents to it, the call is not denied to the account and the role allows
+	/// the call with its arguments. Returns the role's information.
+	///
+	/// # Parameters
+	/// - `who`: The account executing the call.
+	/// - `role_name`: The role with which the call should be dispatched.
+	/// - `call_metadata`: The metadata of the call.
+	/// - `call`: The call to check.
+	pub(crate) fn ensure_can_dispatch_with_role(
+		who: &AccountIdOf<T>,
+		role_name: &RoleNameOf<T>,
+		call_metadata: &T::CallMetadata,
+		call: &T::ExtendedRuntimeCall,
+	) -> Result<RoleInfoOf<T>, DispatchError> {
+		let role_info = Self::check_role_existance_and_version(role_name)?;
+		if !Self::account_implicit_roles(who).contains(role_name) {
+			ensure!(
+				Self::account_roles(who).unwrap_or_default().contains(role_name),
+				Error::<T>::MissingRole,
+			);
+			ensure!(Self::is_assignment_active(who, role_name), Error::<T>::AssignmentNotActive);
+		}
+		ensure!(Self::is_in_scope(who, role_name, Some(call)), Error::<T>::ResourceOutOfScope);
+		ensure!(role_info.is_consented(), Error::<T>::ImpersonationNotConsented);
+		ensure!(!Self::is_call_denied(who, call_metadata), Error::<T>::CallDenied);
+		ensure!(
+			Self::role_grants_call(role_name, call_metadata),
+			Error::<T>::CallNotAttachedToRole
+		);
+		ensure!(
+			Self::role_allows_call(role_name, call_metadata, Some(call)),
+			Error::<T>::CallConstraintViolated,
+		);
+		Ok(role_info)
+	}
+
+	/// Counts a call against the role's spending budget and rate limit and dispatches it with the
+	/// origin inferred from the role. Only fails if role-based dispatch is paused, the call does
+	/// not fit into the spending budget or the rate limit is exceeded; the outcome of the call
+	/// itself is returned inside the `Ok` variant.
+	///
+	/// # Parameters
+	/// - `role_info`: The information of the role with which the call is dispatched.
+	/// - `role_name`: The name of the role with which the call is dispatched.
+	/// - `who`: The account the call is dispatched for.
+	/// - `call`: The call to dispatch.
+	fn dispatch_as_role(
+		role_info: &RoleInfoOf<T>,
+		role_name: &RoleNameOf<T>,
+		who: &AccountIdOf<T>,
+		call: T::ExtendedRuntimeCall,
+	) -> Result<DispatchResultWithPostInfo, DispatchError> {
+		ensure!(Self::active_pause().is_none(), Error::<T>::DispatchPaused);
+		Self::spend_budget(role_name, call.spent_amount())?;
+		Self::use_role(role_name, who)?;
+		let origin_for_dispatch = role_info.infer_origin(who.clone());
+		Ok(if role_info.allow_filter_bypassing {
+			call.dispatch_bypass_filter(origin_for_dispatch.into())
+		} else {
+			call.dispatch(origin_for_dispatch.into())
+		})
+	}
+
+	/// Returns the emergency pause of role-based dispatch if it is in effect at the current block.
+	/// A pause past its end block is no longer in effect, even before its storage entry is removed.
+	pub fn active_pause() -> Option<PauseStateOf<T>> {
+		Self::pause_state().filter(|pause| pause.is_active_at(&System::<T>::block_number()))
+	}
+
+	/// Returns the number of approvals needed to dispatch a call with a role, taking into account
+	/// the thresholds attached to the call for the role and its ancestors. Returns `None` if the
+	/// call can be dispatched directly.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to check.
+	/// - `call_metadata`: The metadata of the call to look for.
+	pub fn required_approvals(
+		role_name: &RoleNameOf<T>,
+		call_metadata: &T::CallMetadata,
+	) -> Option<u32> {
+		Self::role_with_ancestors(role_name)
+			.iter()
+			.filter_map(|name| Self::approval_threshold(call_metadata, name))
+			.max()
+	}
+
+	/// Checks whether a call can be executed by any account regardless of the enforcement mode.
+	///
+	/// # Parameters
+	/// - `call_metadata`: The metadata of the call to look for.
+	pub fn is_public_call(call_metadata: &T::CallMetadata) -> bool {
+		PublicCalls::<T>::contains_key(call_metadata)
+	}
+
+	/// Checks whether a call belongs to this pallet. Such calls check their origins themselves, so
+	/// they are exempt from the `DefaultDeny` enforcement mode.
+	///
+	/// # Parameters
+	/// - `call_metadata`: The metadata of the call to check.
+	pub fn is_own_call(call_metadata: &T::CallMetadata) -> bool {
+		let (pallet_index, _): (u64, u8) = call_metadata.clone().into();
+		pallet_index == <Self as PalletInfoAccess>::index() as u64
+	}
+
+	/// Returns the roles allowed to execute a call, either attached to the call itself or to every
+	/// call of its pallet.
+	///
+	/// # Parameters
+	/// - `call_metadata`: The metadata of the call to look for.
+	pub fn call_granting_roles(call_metadata: &T::CallMetadata) -> BTreeSet<RoleNameOf<T>> {
+		let (pallet_index, _): (u64, u8) = call_metadata.clone().into();
+		let mut granting_roles: BTreeSet<RoleNameOf<T>> =
+			Self::call_roles(call_metadata).unwrap_or_default().into_iter().collect();
+		granting_roles.extend(Self::pallet_roles(pallet_index).unwrap_or_default());
+		granting_roles
+	}
+
+	/// Checks whether a call is attached to a role either directly or through any of the role's
+	/// ancestors which are not suspended.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to check.
+	/// - `call_metadata`: The metadata of the call to look for.
+	pub fn role_grants_call(role_name: &RoleNameOf<T>, call_metadata: &T::CallMetadata) -> bool {
+		let call_roles = Self::call_granting_roles(call_metadata);
+		!call_roles.is_empty() &&
+			Self::role_with_granting_ancestors(role_name)
+				.iter()
+				.any(|name| call_roles.contains(name))
+	}
+
+	/// Checks whether a role allows executing a call, i.e. the role grants the call and the
+	/// constraints attached to the permission, if any, are all satisfied by the call's arguments.
+	///
+	/// Permissions granted through pallet wildcards are never constrained. Constrained permissions
+	/// are not taken into account when the call itself is not provided. Permissions of suspended
+	/// ancestors are not inherited.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the role to check.
+	/// - `call_metadata`: The metadata of the call to look for.
+	/// - `call`: The call whose arguments are checked against the constraints, if available.
+	pub fn role_allows_call(
+		role_name: &RoleNameOf<T>,
+		call_metadata: &T::CallMetadata,
+		call: Option<&T::ExtendedRuntimeCall>,
+	) -> bool {
+		let (pallet_index, _): (u64, u8) = call_metadata.clone().into();
+		let call_roles = Self::call_roles(call_metadata).unwrap_or_default();
+		let pallet_roles = Self::pallet_roles(pallet_index).unwrap_or_default();
+		if call_roles.is_empty() && pallet_roles.is_empty() {
+			return false
+		}
+		Self::role_with_granting_ancestors(role_name).iter().any(|name| {
+			pallet_roles.contains(name) ||
+				(call_roles.contains(name) &&
+					Self::call_constraints(call_metadata, name).map_or(true, |constraints| {
+						call.map_or(false, |call| {
+							constraints.iter().all(|constraint| call.satisfies(constraint))
+						})
+					}))
+		})
+	}
+
+	/// Validates a call against the roles of the account making it, the same way
+	/// `validate_by_metadata` does, but also checks the call's arguments against the
+	/// constraints attached to the roles' permissions. Returns the account's roles allowing the
+	/// call, which is empty if no role is attached to the call at all.
+	///
+	/// # Parameters
+	/// - `call`: The call being validated.
+	/// - `who`: A reference to the account ID making the call.
+	pub fn validate_call(
+		call: &T::ExtendedRuntimeCall,
+		who: &AccountIdOf<T>,
+	) -> Result<Vec<RoleNameOf<T>>, TransactionValidityError> {
+		let call_metadata: T::CallMetadata = call.get_call_metadata_indicies().into();
+		Self::validate_by_roles(call_metadata, Some(call), who)
+	}
+
+	/// Checks that the call is not denied to the account and that the account holds a role
+	/// allowing the call, unless the call is public or no role is attached to it at all and the
+	/// enforcement mode allows such calls or the call belongs to this pallet. Calls with roles
+	/// attached to them are rejected while role-based dispatch is paused, if the pause covers
+	/// the extension. Returns the account's roles allowing the call.
+	fn validate_by_roles(
+		call_metadata: T::CallMetadata,
+		call: Option<&T::ExtendedRuntimeCall>,
+		who: &AccountIdOf<T>,
+	) -> Result<Vec<RoleNameOf<T>>, TransactionValidityError> {
+		ensure!(
+			!Self::is_call_denied(who, &call_metadata),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+		if Self::is_public_call(&call_metadata) {
+			return Ok(Vec::new())
+		}
+		if Self::call_granting_roles(&call_metadata).is_empty() {
+			return match Self::enforcement_mode() {
+				EnforcementMode::DefaultAllow => Ok(Vec::new()),
+				EnforcementMode::DefaultDeny if Self::is_own_call(&call_metadata) => Ok(Vec::new()),
+				EnforcementMode::DefaultDeny =>
+					Err(TransactionValidityError::Invalid(InvalidTransaction::Call)),
+			}
+		}
+		ensure!(
+			!Self::active_pause().map_or(false, |pause| pause.block_extension),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(DISPATCH_PAUSED))
+		);
+		let (suspended_roles, held_roles): (Vec<RoleNameOf<T>>, Vec<RoleNameOf<T>>) =
+			Self::held_roles(who)
+				.into_iter()
+				.filter(|role_name| Self::is_in_scope(who, role_name, call))
+				.partition(|role_name| {
+					Self::roles(role_name).map_or(false, |role_info| role_info.is_suspended())
+				});
+		let allowing_roles: Vec<RoleNameOf<T>> = held_roles
+			.into_iter()
+			.filter(|role_name| {
+				Self::check_role_existance_and_version(role_name)
+					.map_or(false, |role_info| role_info.is_consented())
+			})
+			.filter(|role_name| Self::role_allows_call(role_name, &call_metadata, call))
+			.filter(|role_name| Self::required_approvals(role_name, &call_metadata).is_none())
+			.collect();
+		if allowing_roles.is_empty() {
+			let allowed_by_suspended_role = suspended_roles
+				.iter()
+				.any(|role_name| Self::role_allows_call(role_name, &call_metadata, call));
+			return Err(TransactionValidityError::Invalid(if allowed_by_suspended_role {
+				InvalidTransaction::Custom(ROLE_SUSPENDED)
+			} else {
+				InvalidTransaction::Call
+			}))
+		}
+		Ok(allowing_roles)
+	}
+
+	/// Counts a call against the rate limit of the first of the given roles that has not reached
+	/// it yet. Fails with the `RATE_LIMIT_EXCEEDED` custom code if all of them have.
+	///
+	/// # Parameters
+	/// - `roles`: The account's roles allowing the call.
+	/// - `who`: A reference to the account ID making the call.
+	pub fn use_any_role(
+		roles: &[RoleNameOf<T>],
+		who: &AccountIdOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		if roles.is_empty() {
+			return Ok(())
+		}
+		roles
+			.iter()
+			.any(|role_name| Self::use_role(role_name, who).is_ok())
+			.then_some(())
+			.ok_or(TransactionValidityError::Invalid(InvalidTransaction::Custom(
+				RATE_LIMIT_EXCEEDED,
+			)))
+	}
+
+	/// Checks that at least one of the given roles has not reached its rate limit, without
+	/// counting the call. Fails with the `RATE_LIMIT_EXCEEDED` custom code otherwise.
+	///
+	/// # Parameters
+	/// - `roles`: The account's roles allowing the call.
+	/// - `who`: A reference to the account ID making the call.
+	pub fn ensure_any_role_usable(
+		roles: &[RoleNameOf<T>],
+		who: &AccountIdOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		ensure!(
+			roles.is_empty() || roles.iter().any(|role_name| Self::can_use_role(role_name, who)),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(RATE_LIMIT_EXCEEDED))
+		);
+		Ok(())
+	}
+
+	/// Ensures that a role can start inheriting from a parent role.
+	///
+	/// Walks the ancestors of the parent role and fails with `RoleHierarchyCycle` if the role is
+	/// among them, or with `RoleHierarchyTooDeep` if the chain of parent links starting at the role
+	/// or at any of its descendants would become longer than `MaxRoleHierarchyDepth`.
+	///
+	/// # Parameters
+	/// - `role_name`: The name of the inheriting role.
+	/// - `parent_role_name`: The name of the role to be inherited from.
+	pub fn ensure_can_inherit(
+		role_name: &RoleNameOf<T>,
+		parent_role_name: &RoleNameOf<T>,
+	) -> DispatchResult {
+		let max_depth = T::MaxRoleHierarchyDepth::get();
+		let (_, descendants_depth) = Self::role_with_descendants(role_name)?;
+		let mut depth = descendants_depth.saturating_add(1);
+		let mut current_level = vec![parent_role_name.clone()];
+		loop {
+			ensure!(depth <= max_depth, Error::<T>::RoleHierarchyTooDeep);
+			ensure!(!current_level.contains(role_name), Error::<T>::RoleHierarchyCycle);
+			let mut next_level: Vec<RoleNameOf<T>> = Vec::new();
+			current_level.iter().for_each(|name| {
+				Self::role_parents(name).unwrap_or_default().into_iter().for_each(|parent| {
+					if !next_level.contains(&parent) {
+						next_level.push(parent);
+					}
+				})
+			});
+			if next_level.is_empty() {
+				return Ok(())
+			}
+			current_level = next_level;
+			depth = depth.saturating_add(1);
+		}
+	}
+}
+
+impl<T: Config> CallValidator<T::CallMetadata, AccountIdOf<T>> for Pallet<T> {
+	fn validate_by_metadata(
+		call: T::CallMetadata,
+		who: &AccountIdOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		Self::validate_by_roles(call, None, who).map(|_| ())
 	}
 }
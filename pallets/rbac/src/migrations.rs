@@ -0,0 +1,158 @@
+use crate::{
+	primitives::{RoleDispatchOrigin, RoleInfo, RuntimeVersionHash},
+	AccountRoles, Config, GroupRoles, Pallet, RoleChildren, RoleGroups, RoleHolders, RoleParents,
+	Roles,
+};
+use codec::{Decode, Encode};
+use frame_support::{
+	pallet_prelude::Weight,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+pub mod v1 {
+	use super::*;
+
+	/// The role information stored by the storage version 0 of the pallet.
+	#[derive(Encode, Decode)]
+	pub struct OldRoleInfo<AccountId> {
+		pub consumers_counter: u128,
+		pub runtime_version: RuntimeVersionHash,
+		pub dispatch_origin: RoleDispatchOrigin<AccountId>,
+		pub allow_filter_bypassing: bool,
+	}
+
+	/// Migrates the stored roles to the layout with a rate limit, an impersonation consent and a
+	/// suspension flag. Roles impersonating another account wait for that account's consent
+	/// again.
+	pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 0 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			Roles::<T>::translate::<OldRoleInfo<T::AccountId>, _>(|_, old| {
+				translated += 1;
+				Some(RoleInfo::from_legacy(
+					old.consumers_counter,
+					old.runtime_version,
+					old.dispatch_origin,
+					old.allow_filter_bypassing,
+				))
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			T::DbWeight::get()
+				.reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			Ok((Roles::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let roles_count = u32::decode(&mut &state[..])
+				.map_err(|_| "Expected to decode the number of roles")?;
+			frame_support::ensure!(
+				Roles::<T>::iter().count() as u32 == roles_count,
+				"Expected every role to be migrated"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 1,
+				"Expected the storage version to be updated"
+			);
+			Ok(())
+		}
+	}
+}
+
+pub mod v2 {
+	use super::*;
+
+	/// Indexes the holders of roles by role, filling `RoleHolders` from the roles assigned to
+	/// accounts and `RoleGroups` from the roles assigned to groups, and indexes the role hierarchy
+	/// by parent, filling `RoleChildren` from `RoleParents`. Roles with more children than
+	/// `ChildRolesLimit` keep the children that fit, so the limit must cover the existing
+	/// hierarchy.
+	pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T>::on_chain_storage_version() != 1 {
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			for (who, account_roles) in AccountRoles::<T>::iter() {
+				reads += 1;
+				for role_name in account_roles {
+					RoleHolders::<T>::insert(role_name, &who, ());
+					writes += 1;
+				}
+			}
+			for (group_name, group_roles) in GroupRoles::<T>::iter() {
+				reads += 1;
+				for role_name in group_roles {
+					RoleGroups::<T>::insert(role_name, &group_name, ());
+					writes += 1;
+				}
+			}
+			for (role_name, parents) in RoleParents::<T>::iter() {
+				reads += 1;
+				for parent_role_name in parents {
+					let _ = Pallet::<T>::insert_child_role(&parent_role_name, &role_name);
+					reads += 1;
+					writes += 1;
+				}
+			}
+			StorageVersion::new(2).put::<Pallet<T>>();
+
+			T::DbWeight::get().reads_writes(reads.saturating_add(1), writes.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			let holders: u32 = AccountRoles::<T>::iter_values()
+				.map(|account_roles| account_roles.len() as u32)
+				.sum();
+			let groups: u32 =
+				GroupRoles::<T>::iter_values().map(|group_roles| group_roles.len() as u32).sum();
+			let children: u32 =
+				RoleParents::<T>::iter_values().map(|parents| parents.len() as u32).sum();
+			Ok((holders, groups, children).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			let (holders, groups, children) = <(u32, u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| "Expected to decode the number of role assignments")?;
+			frame_support::ensure!(
+				RoleHolders::<T>::iter_keys().count() as u32 == holders,
+				"Expected every account's role to be indexed"
+			);
+			frame_support::ensure!(
+				RoleGroups::<T>::iter_keys().count() as u32 == groups,
+				"Expected every group's role to be indexed"
+			);
+			frame_support::ensure!(
+				RoleChildren::<T>::iter_values()
+					.map(|children| children.len() as u32)
+					.sum::<u32>() ==
+					children,
+				"Expected every parent link to be indexed"
+			);
+			frame_support::ensure!(
+				Pallet::<T>::on_chain_storage_version() == 2,
+				"Expected the storage version to be updated"
+			);
+			Ok(())
+		}
+	}
+}
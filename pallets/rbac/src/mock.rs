@@ -1,17 +1,21 @@
 use crate::{
 	self as pallet_rbac,
-	primitives::{CallMetadata, ModuleCallIndex},
+	primitives::{CallMetadata, EnforcementMode, ModuleCallIndex},
 	tests_utils::*,
-	traits::GetCallMetadataIndecies,
+	traits::{
+		CallConstraint, CallSpending, GetCallMetadataIndecies, MembershipRule, ResourceExtractor,
+	},
 	AccountRoles, AccountRolesListOf, CallRoles, RoleDispatchOrigin, RoleInfoOf, Roles as RolesMap,
 };
-use codec::Encode;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
+	parameter_types,
 	traits::{ConstU128, ConstU16, ConstU32, ConstU64, GetCallIndex, PalletInfoAccess},
 	Hashable,
 };
 pub(crate) use frame_system::{Call as SystemCall, EnsureRoot, RawOrigin};
 pub(crate) use pallet_balances::Call as BalancesCall;
+use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
@@ -24,6 +28,7 @@ type Balance = u128;
 
 pub(crate) const ALICE: AccountId = 1;
 pub(crate) const BOB: AccountId = 2;
+pub(crate) const CHARLIE: AccountId = 3;
 
 // Configure a mock runtime to test the pallet.
 frame_support::construct_runtime!(
@@ -82,15 +87,46 @@ impl pallet_balances::Config for Test {
 	type MaxHolds = ();
 }
 
+parameter_types! {
+	pub const DefaultEnforcementMode: EnforcementMode = EnforcementMode::DefaultAllow;
+}
+
 impl pallet_rbac::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type ManageOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureRoot<AccountId>;
 	type RoleNameLengthLimit = ConstU32<50>;
+	type RoleDescriptionLimit = ConstU32<64>;
+	type RoleUriLimit = ConstU32<64>;
+	type RoleTagLengthLimit = ConstU32<16>;
+	type RoleTagsLimit = ConstU32<3>;
 	type RolesPerAccountLimit = ConstU32<20>;
+	type ScopesPerAssignmentLimit = ConstU32<2>;
 	type RolesPerCallLimit = ConstU32<20>;
+	type ConstraintsPerCallLimit = ConstU32<3>;
+	type ParentRolesLimit = ConstU32<5>;
+	type ChildRolesLimit = ConstU32<5>;
+	type MaxRoleHierarchyDepth = ConstU32<3>;
+	type MaxExpiriesPerBlock = ConstU32<10>;
+	type ExclusiveRolesLimit = ConstU32<5>;
+	type MaxGroupMembers = ConstU32<5>;
+	type GroupsPerAccountLimit = ConstU32<3>;
+	type RolesPerGroupLimit = ConstU32<5>;
+	type InheritanceChecksLimit = ConstU32<5>;
+	type MembershipRules = MockMembershipRules;
+	type MaxRuleBasedRoles = ConstU32<5>;
+	type RemovalStepsLimit = ConstU32<2>;
+	type MaxPolicyChanges = ConstU32<5>;
 	type CallMetadata = CallMetadata;
 	type ExtendedRuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DefaultEnforcementMode = DefaultEnforcementMode;
+	type ProposalDeposit = ConstU128<100>;
+	type ProposalTimeout = ConstU64<10>;
+	type InvitationTimeout = ConstU64<10>;
+	type MaxApprovals = ConstU32<5>;
+	type MaxProposedCallLength = ConstU32<1024>;
 }
 
 impl GetCallMetadataIndecies for RuntimeCall {
@@ -103,6 +139,75 @@ impl GetCallMetadataIndecies for RuntimeCall {
 	}
 }
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum MockCallConstraint {
+	MaxRemarkLength(u32),
+	MaxNewFree(Balance),
+	BalanceOf(AccountId),
+}
+
+impl CallConstraint for RuntimeCall {
+	type Constraint = MockCallConstraint;
+
+	fn satisfies(&self, constraint: &Self::Constraint) -> bool {
+		match (self, constraint) {
+			(
+				Self::System(SystemCall::remark_with_event { remark }),
+				MockCallConstraint::MaxRemarkLength(max_length),
+			) => remark.len() <= *max_length as usize,
+			(
+				Self::Balances(BalancesCall::force_set_balance { new_free, .. }),
+				MockCallConstraint::MaxNewFree(max_new_free),
+			) => new_free <= max_new_free,
+			(
+				Self::Balances(BalancesCall::force_set_balance { who, .. }),
+				MockCallConstraint::BalanceOf(account),
+			) => who == account,
+			_ => false,
+		}
+	}
+}
+
+impl CallSpending<Balance> for RuntimeCall {
+	fn spent_amount(&self) -> Balance {
+		match self {
+			Self::Balances(BalancesCall::force_set_balance { new_free, .. }) => *new_free,
+			Self::Balances(BalancesCall::transfer_allow_death { value, .. }) |
+			Self::Balances(BalancesCall::transfer_keep_alive { value, .. }) => *value,
+			_ => 0,
+		}
+	}
+}
+
+impl ResourceExtractor for RuntimeCall {
+	type Resource = AccountId;
+
+	fn resource(&self) -> Option<Self::Resource> {
+		match self {
+			Self::Balances(BalancesCall::force_set_balance { who, .. }) => Some(*who),
+			_ => None,
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum MockMembershipRule {
+	MinFreeBalance(Balance),
+}
+
+pub struct MockMembershipRules;
+
+impl MembershipRule<AccountId> for MockMembershipRules {
+	type Rule = MockMembershipRule;
+
+	fn is_satisfied(rule: &Self::Rule, who: &AccountId) -> bool {
+		match rule {
+			MockMembershipRule::MinFreeBalance(min_free) =>
+				Balances::free_balance(who) >= *min_free,
+		}
+	}
+}
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
@@ -122,6 +227,9 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 			(balancer_role(), force_set_balance_metadata().into_inner()),
 		],
 		users: vec![(remarker_role(), ALICE), (balancer_role(), ALICE)],
+		parents: vec![],
+		public_calls: vec![],
+		exclusions: vec![],
 	}
 	.assimilate_storage(&mut t)
 	.unwrap();
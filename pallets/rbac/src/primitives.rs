@@ -13,6 +13,22 @@ use sp_version::RuntimeVersion;
 pub type ModuleCallIndex = (u64, u8);
 pub type RuntimeVersionHash = [u8; 16];
 
+/// Custom `InvalidTransaction` code returned by the `CheckRole` extension when every role allowing
+/// the call has reached its rate limit.
+pub const RATE_LIMIT_EXCEEDED: u8 = 1;
+
+/// Custom `InvalidTransaction` code returned by the `CheckRole` extension when the call is denied
+/// to the account or to any of the roles it holds.
+pub const CALL_DENIED: u8 = 2;
+
+/// Custom `InvalidTransaction` code returned by the `CheckRole` extension when the call is only
+/// allowed by suspended roles held by the account.
+pub const ROLE_SUSPENDED: u8 = 3;
+
+/// Custom `InvalidTransaction` code returned by the `CheckRole` extension when role-based dispatch
+/// is paused and the pause also covers calls with roles attached to them.
+pub const DISPATCH_PAUSED: u8 = 4;
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo, Debug, MaxEncodedLen)]
 pub struct CallMetadata {
 	pub function_index: u8,
@@ -32,6 +48,12 @@ impl CallMetadata {
 	}
 }
 
+impl From<CallMetadata> for ModuleCallIndex {
+	fn from(value: CallMetadata) -> Self {
+		value.into_inner()
+	}
+}
+
 #[derive(TypeInfo, MaxEncodedLen, Encode, Default, Decode, Debug, Clone, PartialEq, Eq)]
 pub enum RoleDispatchOrigin<AccountId> {
 	#[default]
@@ -42,6 +64,267 @@ pub enum RoleDispatchOrigin<AccountId> {
 	Root,
 }
 
+/// The `RoleSettings` struct holds the properties of a role chosen by its managers, which can be
+/// updated without affecting the role's assignments and permissions.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct RoleSettings<AccountId> {
+	/// Whether calls dispatched with the role bypass the call filter of the inferred origin.
+	pub allow_filter_bypassing: bool,
+	/// The origin calls dispatched with the role are dispatched with.
+	pub dispatch_origin: RoleDispatchOrigin<AccountId>,
+}
+
+/// The `RoleMetadata` struct holds a human-readable description of a role, explaining what the
+/// role is for without affecting its permissions.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub struct RoleMetadata<AccountId, Description, Uri, Tags> {
+	/// A free-form description of the role.
+	pub description: Description,
+	/// The account to contact about the role, if any.
+	pub owner: Option<AccountId>,
+	/// A free-form URI of the documents describing the policy the role implements.
+	pub uri: Uri,
+	/// The tags used to categorize the role.
+	pub tags: Tags,
+}
+
+/// The `PolicyChange` enum describes a single change to the role policy, applied along with other
+/// changes as a whole by `apply_policy_changes`.
+#[derive(TypeInfo, Encode, Decode, Debug, Clone, PartialEq, Eq)]
+pub enum PolicyChange<AccountId, RoleName, Call> {
+	/// Creates a new role, as `create_role` does.
+	CreateRole {
+		role_name: RoleName,
+		allow_filter_bypassing: bool,
+		allow_dispatch_as: RoleDispatchOrigin<AccountId>,
+	},
+	/// Adds a call to a role's allowed calls list, as `add_call` does.
+	AddCall { role_name: RoleName, call: Call },
+	/// Removes a call from a role's allowed calls list, as `remove_call` does.
+	RemoveCall { role_name: RoleName, call: Call },
+	/// Assigns a role to an account, as `assign_role` does.
+	AssignRole { who: AccountId, role_name: RoleName },
+	/// Unassigns a role from an account, as `unassign_role` does.
+	UnassignRole { who: AccountId, role_name: RoleName },
+}
+
+/// The `EnforcementMode` enum decides whether calls without any role attached to them are open to
+/// everyone or forbidden to everyone, apart from the calls marked as public.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Default, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnforcementMode {
+	/// Calls without any role attached to them can be executed by any account.
+	#[default]
+	DefaultAllow,
+	/// Calls without any role attached to them cannot be executed by any account.
+	DefaultDeny,
+}
+
+/// The `AssignmentValidity` struct describes the period of blocks during which a role assignment is
+/// in effect. An assignment without bounds is in effect forever.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct AssignmentValidity<BlockNumber> {
+	/// The first block at which the assignment is in effect.
+	pub not_before: Option<BlockNumber>,
+	/// The first block at which the assignment is no longer in effect.
+	pub expires_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> AssignmentValidity<BlockNumber> {
+	/// Checks if the assignment is in effect at the given block.
+	pub fn is_active_at(&self, now: &BlockNumber) -> bool {
+		self.not_before.as_ref().map_or(true, |not_before| now >= not_before) &&
+			!self.is_expired_at(now)
+	}
+
+	/// Checks if the assignment is no longer in effect at the given block and never will be.
+	pub fn is_expired_at(&self, now: &BlockNumber) -> bool {
+		self.expires_at.as_ref().map_or(false, |expires_at| now >= expires_at)
+	}
+}
+
+/// The `PauseState` struct describes an emergency pause of role-based dispatch.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct PauseState<BlockNumber> {
+	/// Whether the `CheckRole` extension also rejects every call with roles attached to it.
+	pub block_extension: bool,
+	/// The first block at which the pause is lifted automatically. A pause without it lasts until
+	/// it is lifted by the pause origin.
+	pub until: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> PauseState<BlockNumber> {
+	/// Checks if the pause is in effect at the given block.
+	pub fn is_active_at(&self, now: &BlockNumber) -> bool {
+		self.until.as_ref().map_or(true, |until| now < until)
+	}
+}
+
+/// The storage a forced removal or a renaming of a role is currently rewriting the references to
+/// the role in. Stages are processed in the order of declaration.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RoleRewriteStage {
+	/// Assignments of the role to accounts.
+	#[default]
+	AccountRoles,
+	/// Permissions of the role to execute calls.
+	CallRoles,
+	/// Calls denied to the role.
+	CallDeniedRoles,
+	/// Pallet wildcards attached to the role.
+	PalletRoles,
+	/// Assignments of the role to groups.
+	GroupRoles,
+	/// Pending invitations to the role.
+	Invitations,
+	/// Parent links from and to the role.
+	RoleParents,
+	/// Admin links from and to the role.
+	RoleAdmins,
+	/// Pending proposals made with the role.
+	Proposals,
+	/// Rate limit usage counted for accounts using the role.
+	AccountRoleUsage,
+}
+
+impl RoleRewriteStage {
+	/// Returns the stage following this one, or `None` if this is the last stage.
+	pub fn next(&self) -> Option<Self> {
+		match self {
+			Self::AccountRoles => Some(Self::CallRoles),
+			Self::CallRoles => Some(Self::CallDeniedRoles),
+			Self::CallDeniedRoles => Some(Self::PalletRoles),
+			Self::PalletRoles => Some(Self::GroupRoles),
+			Self::GroupRoles => Some(Self::Invitations),
+			Self::Invitations => Some(Self::RoleParents),
+			Self::RoleParents => Some(Self::RoleAdmins),
+			Self::RoleAdmins => Some(Self::Proposals),
+			Self::Proposals => Some(Self::AccountRoleUsage),
+			Self::AccountRoleUsage => None,
+		}
+	}
+}
+
+/// The `RoleRewriteCursor` struct tracks the progress of a forced removal or a renaming of a role
+/// spanning several calls.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Default, Clone, PartialEq, Eq, Debug)]
+pub struct RoleRewriteCursor<RawKey> {
+	/// The storage currently being rewritten.
+	pub stage: RoleRewriteStage,
+	/// The raw storage key of the last visited entry of the current stage, if any.
+	pub last_key: Option<RawKey>,
+}
+
+/// The `RoleRename` struct tracks a renaming of a role spanning several calls.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct RoleRename<RoleName, RawKey> {
+	/// The name the role is renamed to.
+	pub new_role_name: RoleName,
+	/// The progress of the renaming.
+	pub cursor: RoleRewriteCursor<RawKey>,
+}
+
+/// The `RoleClone` struct tracks a cloning of a role spanning several calls. Only the
+/// `AccountRoles` and `CallRoles` stages of the cursor are visited.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct RoleClone<RoleName, RawKey> {
+	/// The name of the role being cloned.
+	pub source_role_name: RoleName,
+	/// Whether the accounts the role is assigned to are assigned the clone too.
+	pub with_members: bool,
+	/// The progress of the cloning.
+	pub cursor: RoleRewriteCursor<RawKey>,
+}
+
+/// Defines whose calls are counted against a role's rate limit.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RateLimitScope {
+	/// Every holder of the role has a separate allowance.
+	PerAccount,
+	/// All holders of the role share the same allowance.
+	Global,
+}
+
+/// The `RateLimit` struct caps how many calls can be dispatched using a role within a period of
+/// blocks.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RateLimit {
+	/// The maximum number of calls per period.
+	pub max_calls: u32,
+	/// The length of the period in blocks.
+	pub period: u32,
+	/// Defines whose calls are counted together.
+	pub scope: RateLimitScope,
+}
+
+impl RateLimit {
+	/// Checks if the rate limit allows any calls and has a non-empty period.
+	pub fn is_valid(&self) -> bool {
+		!self.max_calls.is_zero() && !self.period.is_zero()
+	}
+}
+
+/// The `UsageWindow` struct tracks the number of calls dispatched using a role within the current
+/// rate limit period.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct UsageWindow<BlockNumber> {
+	/// The first block of the period.
+	pub window_start: BlockNumber,
+	/// The number of calls dispatched within the period.
+	pub calls: u32,
+}
+
+/// The `SpendingBudget` struct caps the amount of funds that can be moved by calls dispatched
+/// using a role within a period of blocks, across all holders of the role.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct SpendingBudget<Balance> {
+	/// The maximum amount of funds moved per period.
+	pub limit: Balance,
+	/// The length of the period in blocks, not eras. Periods start at every multiple of it.
+	pub period: u32,
+}
+
+/// The `SpendingWindow` struct tracks the amount of funds moved using a role within the current
+/// budget period.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct SpendingWindow<Balance, BlockNumber> {
+	/// The first block of the period.
+	pub window_start: BlockNumber,
+	/// The amount of funds moved within the period.
+	pub spent: Balance,
+}
+
+/// The `CallProposal` struct holds a call proposed by a holder of a role whose permission to
+/// execute the call requires approvals from several holders of the role.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Clone, PartialEq, Eq, Debug)]
+pub struct CallProposal<AccountId, BlockNumber, Balance, EncodedCall, Approvals> {
+	/// The account which proposed the call and whose deposit is reserved.
+	pub proposer: AccountId,
+	/// The encoded call.
+	pub call: EncodedCall,
+	/// The holders of the role which approved the call, including the proposer.
+	pub approvals: Approvals,
+	/// The number of approvals needed to dispatch the call.
+	pub threshold: u32,
+	/// The first block at which the proposal can no longer be approved.
+	pub expires_at: BlockNumber,
+	/// The amount reserved from the proposer until the proposal is dispatched or dropped.
+	pub deposit: Balance,
+}
+
+/// The `ImpersonationConsent` enum tracks whether the account a role dispatches calls as has
+/// agreed to be impersonated by the role's holders.
+#[derive(TypeInfo, MaxEncodedLen, Encode, Decode, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImpersonationConsent {
+	/// The role does not dispatch calls as another account.
+	NotRequired,
+	/// The impersonated account has not accepted the impersonation yet.
+	Pending,
+	/// The impersonated account has accepted the impersonation.
+	Given,
+	/// The impersonated account has revoked the impersonation.
+	Revoked,
+}
+
 /// The `RoleInfo` struct holds information about a counter tracking how many consumers are using
 /// this role.
 #[derive(TypeInfo, MaxEncodedLen, Encode, Decode, PartialEq, Eq, Debug)]
@@ -50,6 +333,9 @@ pub struct RoleInfo<AccountId> {
 	runtime_version: RuntimeVersionHash,
 	dispatch_origin: RoleDispatchOrigin<AccountId>,
 	pub allow_filter_bypassing: bool,
+	rate_limit: Option<RateLimit>,
+	consent: ImpersonationConsent,
+	suspended: bool,
 }
 
 impl<AccountId: Clone> RoleInfo<AccountId> {
@@ -59,11 +345,18 @@ impl<AccountId: Clone> RoleInfo<AccountId> {
 		dispatch_origin: RoleDispatchOrigin<AccountId>,
 	) -> Self {
 		let hashed_runtime_version: RuntimeVersionHash = runtime_version.encode().twox_128();
+		let consent = match dispatch_origin {
+			RoleDispatchOrigin::SignedAs { .. } => ImpersonationConsent::Pending,
+			_ => ImpersonationConsent::NotRequired,
+		};
 		Self {
 			runtime_version: hashed_runtime_version,
 			allow_filter_bypassing,
 			dispatch_origin,
 			consumers_counter: 0u128,
+			rate_limit: None,
+			consent,
+			suspended: false,
 		}
 	}
 
@@ -128,6 +421,117 @@ impl<AccountId: Clone> RoleInfo<AccountId> {
 		}
 	}
 
+	/// Returns whether calls dispatched with the role are dispatched as the account holding it,
+	/// i.e. the role does not grant any privileged origin.
+	pub fn dispatches_as_holder(&self) -> bool {
+		matches!(self.dispatch_origin, RoleDispatchOrigin::Regular)
+	}
+
+	/// Returns the properties of the role which can be updated in place.
+	pub fn settings(&self) -> RoleSettings<AccountId> {
+		RoleSettings {
+			allow_filter_bypassing: self.allow_filter_bypassing,
+			dispatch_origin: self.dispatch_origin.clone(),
+		}
+	}
+
+	/// Updates the properties of the role. Impersonating another account requires the consent of
+	/// that account again, while the consent is kept if the impersonated account does not change.
+	pub fn set_settings(&mut self, settings: RoleSettings<AccountId>)
+	where
+		AccountId: PartialEq,
+	{
+		self.consent = match &settings.dispatch_origin {
+			RoleDispatchOrigin::SignedAs { who } if self.impersonated_account() == Some(who) =>
+				self.consent,
+			RoleDispatchOrigin::SignedAs { .. } => ImpersonationConsent::Pending,
+			_ => ImpersonationConsent::NotRequired,
+		};
+		self.allow_filter_bypassing = settings.allow_filter_bypassing;
+		self.dispatch_origin = settings.dispatch_origin;
+	}
+
+	/// Returns the rate limit of the role, if any.
+	pub fn rate_limit(&self) -> Option<RateLimit> {
+		self.rate_limit
+	}
+
+	/// Sets or clears the rate limit of the role.
+	pub fn set_rate_limit(&mut self, rate_limit: Option<RateLimit>) {
+		self.rate_limit = rate_limit;
+	}
+
+	/// Returns the account the role dispatches calls as, if any.
+	pub fn impersonated_account(&self) -> Option<&AccountId> {
+		match &self.dispatch_origin {
+			RoleDispatchOrigin::SignedAs { who } => Some(who),
+			_ => None,
+		}
+	}
+
+	/// Returns the consent of the impersonated account.
+	pub fn consent(&self) -> ImpersonationConsent {
+		self.consent
+	}
+
+	/// Records the consent of the impersonated account.
+	pub fn set_consent(&mut self, consent: ImpersonationConsent) {
+		self.consent = consent;
+	}
+
+	/// Checks whether calls can be dispatched with the role, i.e. the role does not impersonate
+	/// any account or the impersonated account has accepted the impersonation.
+	pub fn is_consented(&self) -> bool {
+		matches!(self.consent, ImpersonationConsent::NotRequired | ImpersonationConsent::Given)
+	}
+
+	/// Checks whether the role is suspended, i.e. it grants no permissions until resumed.
+	pub fn is_suspended(&self) -> bool {
+		self.suspended
+	}
+
+	/// Suspends or resumes the role.
+	pub fn set_suspended(&mut self, suspended: bool) {
+		self.suspended = suspended;
+	}
+
+	/// Builds the role information stored before the rate limit, the impersonation consent and
+	/// the suspension were introduced. Roles impersonating another account require that account's
+	/// consent, just like newly created ones.
+	pub fn from_legacy(
+		consumers_counter: u128,
+		runtime_version: RuntimeVersionHash,
+		dispatch_origin: RoleDispatchOrigin<AccountId>,
+		allow_filter_bypassing: bool,
+	) -> Self {
+		let consent = match dispatch_origin {
+			RoleDispatchOrigin::SignedAs { .. } => ImpersonationConsent::Pending,
+			_ => ImpersonationConsent::NotRequired,
+		};
+		Self {
+			consumers_counter,
+			runtime_version,
+			dispatch_origin,
+			allow_filter_bypassing,
+			rate_limit: None,
+			consent,
+			suspended: false,
+		}
+	}
+
+	/// Returns a copy of the role's settings which is not used by anything yet.
+	pub fn without_consumers(&self) -> Self {
+		Self {
+			consumers_counter: 0u128,
+			runtime_version: self.runtime_version,
+			dispatch_origin: self.dispatch_origin.clone(),
+			allow_filter_bypassing: self.allow_filter_bypassing,
+			rate_limit: self.rate_limit,
+			consent: self.consent,
+			suspended: self.suspended,
+		}
+	}
+
 	#[cfg(test)]
 	pub(crate) fn get_consumers_counter(&self) -> u128 {
 		self.consumers_counter
@@ -140,6 +544,14 @@ impl<AccountId: Clone> RoleInfo<AccountId> {
 		allow_filter_bypassing: bool,
 		dispatch_origin: RoleDispatchOrigin<AccountId>,
 	) -> Self {
-		Self { consumers_counter, runtime_version, dispatch_origin, allow_filter_bypassing }
+		Self {
+			consumers_counter,
+			runtime_version,
+			dispatch_origin,
+			allow_filter_bypassing,
+			rate_limit: None,
+			consent: ImpersonationConsent::NotRequired,
+			suspended: false,
+		}
 	}
 }
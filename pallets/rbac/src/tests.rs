@@ -1,9 +1,31 @@
-use crate::{mock::*, tests_utils::*, CheckRole, RoleInfo};
-use frame_support::{assert_err, assert_noop, assert_ok, dispatch::DispatchInfo, Hashable};
+use crate::{
+	migrations::{
+		v1::{MigrateToV1, OldRoleInfo},
+		v2::MigrateToV2,
+	},
+	mock::*,
+	primitives::{
+		EnforcementMode, ImpersonationConsent, PauseState, PolicyChange, RoleSettings, CALL_DENIED,
+		DISPATCH_PAUSED, RATE_LIMIT_EXCEEDED, ROLE_SUSPENDED,
+	},
+	tests_utils::*,
+	traits::GetCallMetadataIndecies,
+	AssignmentValidity, CallValidator, CheckRole, RateLimit, RateLimitScope, RoleInfo,
+	SpendingBudget, SpendingWindow,
+};
+use codec::Encode;
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	dispatch::DispatchInfo,
+	storage::unhashed,
+	traits::{Currency, GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+	Hashable,
+};
 use sp_runtime::{
 	traits::SignedExtension,
 	transaction_validity::{InvalidTransaction, TransactionValidityError},
-	DispatchError,
+	BuildStorage, DispatchError,
 };
 
 #[test]
@@ -433,3 +455,3507 @@ fn obsolete_role_should_prevent_check_role() {
 		);
 	});
 }
+
+#[test]
+fn add_parent_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::ParentRoleAdded {
+				role_name: default_empty_role(),
+				parent_role_name: remarker_role(),
+			}
+			.into(),
+		);
+
+		assert!(Roles::role_parents(default_empty_role()).unwrap().contains(&remarker_role()));
+		assert!(Roles::role_children(remarker_role()).unwrap().contains(&default_empty_role()));
+		assert_consumers_counter_eq(&default_empty_role(), 1);
+		assert_consumers_counter_eq(&remarker_role(), 3);
+	});
+}
+
+#[test]
+fn already_added_parent_role_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+
+		assert_noop!(
+			Roles::add_parent_role(root(), default_empty_role(), remarker_role()),
+			RolesError::ParentRoleAlreadyAdded
+		);
+	});
+}
+
+#[test]
+fn cycle_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::add_parent_role(root(), remarker_role(), remarker_role()),
+			RolesError::RoleHierarchyCycle
+		);
+
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		assert_ok!(Roles::add_parent_role(root(), remarker_role(), balancer_role()));
+		assert_noop!(
+			Roles::add_parent_role(root(), balancer_role(), default_empty_role()),
+			RolesError::RoleHierarchyCycle
+		);
+	});
+}
+
+#[test]
+fn too_deep_hierarchy_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		let chain: Vec<_> = (0u8..5).map(|index| role_name(&[b'L', b'0' + index])).collect();
+		chain.iter().for_each(|name| {
+			assert_ok!(Roles::create_role(
+				root(),
+				name.clone(),
+				false,
+				crate::RoleDispatchOrigin::Regular
+			));
+		});
+		assert_ok!(Roles::add_parent_role(root(), chain[3].clone(), chain[4].clone()));
+		assert_ok!(Roles::add_parent_role(root(), chain[2].clone(), chain[3].clone()));
+		assert_ok!(Roles::add_parent_role(root(), chain[1].clone(), chain[2].clone()));
+
+		assert_noop!(
+			Roles::add_parent_role(root(), chain[0].clone(), chain[1].clone()),
+			RolesError::RoleHierarchyTooDeep
+		);
+	});
+}
+
+#[test]
+fn too_deep_descendants_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		let chain: Vec<_> = (0u8..5).map(|index| role_name(&[b'L', b'0' + index])).collect();
+		chain.iter().for_each(|name| {
+			assert_ok!(Roles::create_role(
+				root(),
+				name.clone(),
+				false,
+				crate::RoleDispatchOrigin::Regular
+			));
+		});
+		assert_ok!(Roles::add_parent_role(root(), chain[0].clone(), chain[1].clone()));
+		assert_ok!(Roles::add_parent_role(root(), chain[1].clone(), chain[2].clone()));
+		assert_ok!(Roles::add_parent_role(root(), chain[3].clone(), chain[4].clone()));
+
+		assert_noop!(
+			Roles::add_parent_role(root(), chain[2].clone(), chain[3].clone()),
+			RolesError::RoleHierarchyTooDeep
+		);
+		assert_eq!(Roles::role_with_descendants(&chain[2]).unwrap().1, 2);
+	});
+}
+
+#[test]
+fn too_many_child_roles_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		let children: Vec<_> = (0u8..6).map(|index| role_name(&[b'C', b'0' + index])).collect();
+		children.iter().for_each(|name| {
+			assert_ok!(Roles::create_role(
+				root(),
+				name.clone(),
+				false,
+				crate::RoleDispatchOrigin::Regular
+			));
+		});
+		children[..5].iter().for_each(|name| {
+			assert_ok!(Roles::add_parent_role(root(), name.clone(), remarker_role()));
+		});
+
+		assert_noop!(
+			Roles::add_parent_role(root(), children[5].clone(), remarker_role()),
+			RolesError::TooManyChildRoles
+		);
+		assert_eq!(Roles::role_with_descendants(&remarker_role()).unwrap().0.len(), 6);
+	});
+}
+
+#[test]
+fn remove_parent_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+
+		assert_ok!(Roles::remove_parent_role(root(), default_empty_role(), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::ParentRoleRemoved {
+				role_name: default_empty_role(),
+				parent_role_name: remarker_role(),
+			}
+			.into(),
+		);
+
+		assert!(Roles::role_parents(default_empty_role()).is_none());
+		assert!(Roles::role_children(remarker_role()).is_none());
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+	});
+}
+
+#[test]
+fn non_added_parent_role_should_prevent_remove_parent_role() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::remove_parent_role(root(), default_empty_role(), remarker_role()),
+			RolesError::ParentRoleNotAdded
+		);
+	});
+}
+
+#[test]
+fn rename_role_should_move_child_roles() {
+	new_test_ext().execute_with(|| {
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		assert_ok!(Roles::add_parent_role(root(), remarker_role(), balancer_role()));
+
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		while Roles::roles(remarker_role()).is_some() {
+			assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		}
+
+		assert!(Roles::role_children(remarker_role()).is_none());
+		assert!(Roles::role_children(&scribe).unwrap().contains(&default_empty_role()));
+		assert!(Roles::role_children(balancer_role()).unwrap().contains(&scribe));
+		assert!(!Roles::role_children(balancer_role()).unwrap().contains(&remarker_role()));
+		assert_eq!(Roles::role_with_descendants(&balancer_role()).unwrap().1, 2);
+	});
+}
+
+#[test]
+fn dispatch_call_with_inherited_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), default_empty_role()),
+			RolesError::CallNotAttachedToRole
+		);
+
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			remark_call(),
+			default_empty_role()
+		));
+		System::assert_last_event(
+			RolesEvent::CallDispatchedWithRole {
+				role_name: default_empty_role(),
+				who: BOB,
+				call_metadata: remark_metadata(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn check_role_with_inherited_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+		assert_err!(
+			CheckRole::<Test>::new().pre_dispatch(
+				&BOB,
+				&remark_call(),
+				&DispatchInfo::default(),
+				1
+			),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+
+		assert_ok!(CheckRole::<Test>::new().pre_dispatch(
+			&BOB,
+			&remark_call(),
+			&DispatchInfo::default(),
+			1
+		));
+	});
+}
+
+#[test]
+fn assign_role_with_validity_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+
+		assert_ok!(Roles::assign_role_with_validity(
+			root(),
+			BOB,
+			default_empty_role(),
+			Some(2),
+			Some(5)
+		));
+		let validity = AssignmentValidity { not_before: Some(2), expires_at: Some(5) };
+		System::assert_has_event(
+			RolesEvent::AccountAssignedToRole { role_name: default_empty_role(), who: BOB }.into(),
+		);
+		System::assert_last_event(
+			RolesEvent::AssignmentValiditySet {
+				role_name: default_empty_role(),
+				who: BOB,
+				validity: validity.clone(),
+			}
+			.into(),
+		);
+
+		assert!(account_set_contains(&BOB, &default_empty_role()));
+		assert_eq!(Roles::assignment_validity(BOB, default_empty_role()), Some(validity));
+		assert_eq!(Roles::expiring_assignments(5).into_inner(), vec![(BOB, default_empty_role())]);
+		assert_consumers_counter_eq(&default_empty_role(), 1);
+	});
+}
+
+#[test]
+fn invalid_validity_should_prevent_assign_role_with_validity() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::assign_role_with_validity(root(), BOB, default_empty_role(), None, Some(1)),
+			RolesError::InvalidAssignmentValidity
+		);
+		assert_noop!(
+			Roles::assign_role_with_validity(root(), BOB, default_empty_role(), Some(5), Some(5)),
+			RolesError::InvalidAssignmentValidity
+		);
+	});
+}
+
+#[test]
+fn not_yet_active_assignment_should_prevent_dispatch_call_with_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role_with_validity(root(), BOB, remarker_role(), Some(3), None));
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), remarker_role()),
+			RolesError::AssignmentNotActive
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+
+		System::set_block_number(3);
+
+		assert_ok!(Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), remarker_role()));
+		assert_ok!(check_role_pre_dispatch(&BOB, &remark_call()));
+	});
+}
+
+#[test]
+fn expired_assignment_should_prevent_dispatch_call_with_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role_with_validity(root(), BOB, remarker_role(), None, Some(3)));
+		assert_ok!(check_role_pre_dispatch(&BOB, &remark_call()));
+
+		System::set_block_number(3);
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), remarker_role()),
+			RolesError::AssignmentNotActive
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_remove_expired_assignments() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role_with_validity(root(), BOB, remarker_role(), None, Some(3)));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		Roles::on_idle(2, Weight::MAX);
+		assert!(account_set_contains(&BOB, &remarker_role()));
+
+		System::set_block_number(3);
+		Roles::on_idle(3, Weight::MAX);
+		System::assert_last_event(
+			RolesEvent::AssignmentExpired { role_name: remarker_role(), who: BOB }.into(),
+		);
+
+		assert!(!account_set_contains(&BOB, &remarker_role()));
+		assert!(Roles::assignment_validity(BOB, remarker_role()).is_none());
+		assert!(Roles::expiring_assignments(3).is_empty());
+		assert_eq!(Roles::last_expiry_cleanup(), 3);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+	});
+}
+
+#[test]
+fn on_idle_should_skip_reassigned_assignments() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role_with_validity(root(), BOB, remarker_role(), None, Some(3)));
+		assert_ok!(Roles::unassign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+
+		System::set_block_number(3);
+		Roles::on_idle(3, Weight::MAX);
+
+		assert!(account_set_contains(&BOB, &remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+	});
+}
+
+#[test]
+fn on_idle_should_respect_remaining_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role_with_validity(root(), BOB, remarker_role(), None, Some(3)));
+
+		System::set_block_number(3);
+		assert_eq!(Roles::on_idle(3, Weight::zero()), Weight::zero());
+
+		assert!(account_set_contains(&BOB, &remarker_role()));
+		assert!(!Roles::expiring_assignments(3).is_empty());
+	});
+}
+
+#[test]
+fn set_role_admin_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), Some(remarker_role())));
+		System::assert_last_event(
+			RolesEvent::RoleAdminSet {
+				role_name: default_empty_role(),
+				admin_role_name: Some(remarker_role()),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::role_admin(default_empty_role()), Some(remarker_role()));
+		assert_consumers_counter_eq(&default_empty_role(), 1);
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), None));
+		System::assert_last_event(
+			RolesEvent::RoleAdminSet { role_name: default_empty_role(), admin_role_name: None }
+				.into(),
+		);
+		assert!(Roles::role_admin(default_empty_role()).is_none());
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+	});
+}
+
+#[test]
+fn non_manage_origin_should_prevent_set_role_admin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::set_role_admin(signed_as(ALICE), default_empty_role(), Some(remarker_role())),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn admin_role_holder_should_manage_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), Some(remarker_role())));
+		assert!(account_set_contains(&ALICE, &remarker_role()));
+
+		assert_ok!(Roles::assign_role(signed_as(ALICE), BOB, default_empty_role()));
+		assert!(account_set_contains(&BOB, &default_empty_role()));
+		assert_ok!(Roles::add_call(signed_as(ALICE), default_empty_role(), remark_call()));
+		assert!(call_set_contains(&remark_metadata(), &default_empty_role()));
+		assert_ok!(Roles::remove_call(signed_as(ALICE), default_empty_role(), remark_call()));
+		assert!(!call_set_contains(&remark_metadata(), &default_empty_role()));
+		assert_ok!(Roles::unassign_role(signed_as(ALICE), BOB, default_empty_role()));
+		assert!(!account_set_contains(&BOB, &default_empty_role()));
+	});
+}
+
+#[test]
+fn non_admin_role_holder_should_not_manage_role() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::assign_role(signed_as(ALICE), BOB, default_empty_role()),
+			RolesError::BadOrigin
+		);
+
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), Some(remarker_role())));
+		assert_noop!(
+			Roles::assign_role(signed_as(BOB), BOB, default_empty_role()),
+			RolesError::BadOrigin
+		);
+		assert_noop!(
+			Roles::assign_role(signed_as(ALICE), BOB, balancer_role()),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn admin_role_holder_should_not_manage_privileged_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_role_admin(root(), balancer_role(), Some(remarker_role())));
+		assert_noop!(
+			Roles::add_call(signed_as(ALICE), balancer_role(), remark_call()),
+			RolesError::PrivilegedRole
+		);
+		assert_noop!(
+			Roles::add_pallet_wildcard(signed_as(ALICE), balancer_role(), 0),
+			RolesError::PrivilegedRole
+		);
+		assert_noop!(
+			Roles::assign_role(signed_as(ALICE), BOB, balancer_role()),
+			RolesError::PrivilegedRole
+		);
+
+		let impersonator = impersonating_role(CHARLIE);
+		assert_ok!(Roles::set_role_admin(root(), impersonator.clone(), Some(remarker_role())));
+		assert_noop!(
+			Roles::assign_role(signed_as(ALICE), ALICE, impersonator),
+			RolesError::PrivilegedRole
+		);
+	});
+}
+
+#[test]
+fn admin_role_holder_should_not_manage_filter_bypassing_role() {
+	new_test_ext().execute_with(|| {
+		let bypasser = role_name(b"Bypasser");
+		assert_ok!(Roles::create_role(
+			root(),
+			bypasser.clone(),
+			true,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::set_role_admin(root(), bypasser.clone(), Some(remarker_role())));
+
+		assert_noop!(
+			Roles::assign_role(signed_as(ALICE), BOB, bypasser.clone()),
+			RolesError::PrivilegedRole
+		);
+		assert_noop!(
+			Roles::add_call(signed_as(ALICE), bypasser.clone(), remark_call()),
+			RolesError::PrivilegedRole
+		);
+		assert_ok!(Roles::assign_role(root(), BOB, bypasser));
+	});
+}
+
+#[test]
+fn add_pallet_wildcard_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+
+		assert_ok!(Roles::add_pallet_wildcard(
+			root(),
+			default_empty_role(),
+			balances_pallet_index()
+		));
+		System::assert_last_event(
+			RolesEvent::PalletWildcardAddedToRole {
+				role_name: default_empty_role(),
+				pallet_index: balances_pallet_index(),
+			}
+			.into(),
+		);
+
+		assert!(Roles::pallet_roles(balances_pallet_index())
+			.unwrap()
+			.contains(&default_empty_role()));
+		assert_consumers_counter_eq(&default_empty_role(), 1);
+	});
+}
+
+#[test]
+fn already_added_pallet_wildcard_should_prevent_add_pallet_wildcard() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_pallet_wildcard(
+			root(),
+			default_empty_role(),
+			balances_pallet_index()
+		));
+
+		assert_noop!(
+			Roles::add_pallet_wildcard(root(), default_empty_role(), balances_pallet_index()),
+			RolesError::PalletWildcardAlreadyAttachedToRole
+		);
+	});
+}
+
+#[test]
+fn remove_pallet_wildcard_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_pallet_wildcard(
+			root(),
+			default_empty_role(),
+			balances_pallet_index()
+		));
+
+		assert_ok!(Roles::remove_pallet_wildcard(
+			root(),
+			default_empty_role(),
+			balances_pallet_index()
+		));
+		System::assert_last_event(
+			RolesEvent::PalletWildcardRemovedFromRole {
+				role_name: default_empty_role(),
+				pallet_index: balances_pallet_index(),
+			}
+			.into(),
+		);
+
+		assert!(!Roles::pallet_roles(balances_pallet_index())
+			.unwrap_or_default()
+			.contains(&default_empty_role()));
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+	});
+}
+
+#[test]
+fn non_attached_pallet_wildcard_should_prevent_remove_pallet_wildcard() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::remove_pallet_wildcard(root(), default_empty_role(), balances_pallet_index()),
+			RolesError::PalletWildcardNotAttachedToRole
+		);
+	});
+}
+
+#[test]
+fn dispatch_call_with_pallet_wildcard_should_work() {
+	new_test_ext().execute_with(|| {
+		let role_name = role_name(b"Treasury");
+		assert_ok!(Roles::create_role(
+			root(),
+			role_name.clone(),
+			false,
+			crate::RoleDispatchOrigin::Root
+		));
+		assert_ok!(Roles::assign_role(root(), BOB, role_name.clone()));
+		assert_ok!(Roles::add_pallet_wildcard(root(), role_name.clone(), balances_pallet_index()));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+		System::assert_last_event(
+			RolesEvent::CallDispatchedWithRole {
+				role_name: role_name.clone(),
+				who: BOB,
+				call_metadata: force_set_balance_metadata(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), role_name),
+			RolesError::CallNotAttachedToRole
+		);
+	});
+}
+
+#[test]
+fn check_role_with_pallet_wildcard_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_pallet_wildcard(
+			root(),
+			default_empty_role(),
+			balances_pallet_index()
+		));
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &force_set_balance_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+
+		assert_ok!(check_role_pre_dispatch(&BOB, &force_set_balance_call()));
+		assert_ok!(check_role_pre_dispatch(&BOB, &deprecated_call()));
+	});
+}
+
+#[test]
+fn set_call_constraints_should_work() {
+	new_test_ext().execute_with(|| {
+		let constraints = call_constraints(vec![MockCallConstraint::MaxRemarkLength(3)]);
+
+		assert_ok!(Roles::set_call_constraints(
+			root(),
+			remarker_role(),
+			remark_call(),
+			constraints.clone()
+		));
+		System::assert_last_event(
+			RolesEvent::CallConstraintsSet {
+				role_name: remarker_role(),
+				call_metadata: remark_metadata(),
+				constraints: constraints.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::call_constraints(remark_metadata(), remarker_role()), Some(constraints));
+
+		assert_ok!(Roles::set_call_constraints(
+			root(),
+			remarker_role(),
+			remark_call(),
+			call_constraints(vec![])
+		));
+		assert!(Roles::call_constraints(remark_metadata(), remarker_role()).is_none());
+	});
+}
+
+#[test]
+fn non_attached_call_should_prevent_set_call_constraints() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::set_call_constraints(
+				root(),
+				default_empty_role(),
+				remark_call(),
+				call_constraints(vec![MockCallConstraint::MaxRemarkLength(3)])
+			),
+			RolesError::CallNotAttachedToRole
+		);
+	});
+}
+
+#[test]
+fn remove_call_should_remove_call_constraints() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_call_constraints(
+			root(),
+			remarker_role(),
+			remark_call(),
+			call_constraints(vec![MockCallConstraint::MaxRemarkLength(3)])
+		));
+
+		assert_ok!(Roles::remove_call(root(), remarker_role(), remark_call()));
+
+		assert!(Roles::call_constraints(remark_metadata(), remarker_role()).is_none());
+	});
+}
+
+#[test]
+fn violated_call_constraint_should_prevent_dispatch_call_with_role() {
+	new_test_ext().execute_with(|| {
+		let long_remark_call =
+			Box::new(RuntimeCall::System(SystemCall::remark_with_event { remark: vec![0; 4] }));
+		assert_ok!(Roles::set_call_constraints(
+			root(),
+			remarker_role(),
+			remark_call(),
+			call_constraints(vec![MockCallConstraint::MaxRemarkLength(3)])
+		));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), long_remark_call, remarker_role()),
+			RolesError::CallConstraintViolated
+		);
+	});
+}
+
+#[test]
+fn violated_call_constraint_should_prevent_check_role() {
+	new_test_ext().execute_with(|| {
+		let other_balance_call =
+			RuntimeCall::Balances(BalancesCall::force_set_balance { who: BOB, new_free: 0 });
+		assert_ok!(Roles::set_call_constraints(
+			root(),
+			balancer_role(),
+			force_set_balance_call(),
+			call_constraints(vec![MockCallConstraint::BalanceOf(ALICE)])
+		));
+
+		assert_ok!(check_role_pre_dispatch(&ALICE, &force_set_balance_call()));
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &other_balance_call),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn all_call_constraints_should_be_satisfied() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_call_constraints(
+			root(),
+			balancer_role(),
+			force_set_balance_call(),
+			call_constraints(vec![
+				MockCallConstraint::BalanceOf(ALICE),
+				MockCallConstraint::MaxNewFree(10),
+			])
+		));
+
+		assert_ok!(check_role_pre_dispatch(&ALICE, &force_set_balance_call_with(10)));
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &force_set_balance_call_with(11)),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &force_set_balance_call_for(BOB)),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn set_role_rate_limit_should_work() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 2, period: 10, scope: RateLimitScope::PerAccount };
+
+		assert_ok!(Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)));
+		System::assert_last_event(
+			RolesEvent::RoleRateLimitSet {
+				role_name: remarker_role(),
+				rate_limit: Some(rate_limit),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::roles(remarker_role()).unwrap().rate_limit(), Some(rate_limit));
+
+		assert_ok!(Roles::set_role_rate_limit(root(), remarker_role(), None));
+		assert_eq!(Roles::roles(remarker_role()).unwrap().rate_limit(), None);
+	});
+}
+
+#[test]
+fn set_role_rate_limit_should_fail_while_role_is_renamed() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 2, period: 10, scope: RateLimitScope::PerAccount };
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), CHARLIE, remarker_role()));
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		assert!(Roles::role_rename(remarker_role()).is_some());
+
+		assert_noop!(
+			Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::set_role_rate_limit(root(), scribe, Some(rate_limit)),
+			RolesError::RoleBeingRenamed
+		);
+	});
+}
+
+#[test]
+fn invalid_rate_limit_should_prevent_set_role_rate_limit() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 0, period: 10, scope: RateLimitScope::PerAccount };
+
+		assert_noop!(
+			Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)),
+			RolesError::InvalidRateLimit
+		);
+		assert_noop!(
+			Roles::set_role_rate_limit(root(), role_name(b"NoRole"), None),
+			RolesError::RoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn rate_limit_should_prevent_dispatch_call_with_role() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 2, period: 10, scope: RateLimitScope::PerAccount };
+		assert_ok!(Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)));
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::RateLimitExceeded
+		);
+		assert_ok!(Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), remarker_role()));
+
+		System::set_block_number(10);
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+	});
+}
+
+#[test]
+fn global_rate_limit_should_be_shared_by_role_holders() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 1, period: 10, scope: RateLimitScope::Global };
+		assert_ok!(Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)));
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), remarker_role()),
+			RolesError::RateLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn rate_limit_should_prevent_check_role() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 1, period: 10, scope: RateLimitScope::PerAccount };
+		assert_ok!(Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)));
+
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(RATE_LIMIT_EXCEEDED))
+		);
+
+		System::set_block_number(10);
+
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+	});
+}
+
+#[test]
+fn check_role_validate_should_report_custom_codes() {
+	new_test_ext().execute_with(|| {
+		let rate_limit = RateLimit { max_calls: 1, period: 10, scope: RateLimitScope::PerAccount };
+		assert_ok!(Roles::set_role_rate_limit(root(), remarker_role(), Some(rate_limit)));
+
+		assert_ok!(check_role_validate(&ALICE, &remark_call()));
+		assert_ok!(check_role_validate(&ALICE, &remark_call()));
+		assert!(Roles::account_role_usage(remarker_role(), ALICE).is_none());
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+		assert_err!(
+			check_role_validate(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(RATE_LIMIT_EXCEEDED))
+		);
+
+		assert_ok!(Roles::suspend_role(root(), remarker_role()));
+		assert_err!(
+			check_role_validate(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(ROLE_SUSPENDED))
+		);
+		assert_ok!(Roles::resume_role(root(), remarker_role()));
+
+		assert_ok!(Roles::pause_role_dispatch(root(), true, None));
+		assert_err!(
+			check_role_validate(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(DISPATCH_PAUSED))
+		);
+		assert_ok!(Roles::unpause_role_dispatch(root()));
+
+		assert_ok!(Roles::add_account_denial(root(), ALICE, remark_call()));
+		assert_err!(
+			check_role_validate(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+	});
+}
+
+#[test]
+fn remove_role_should_clear_role_usage() {
+	new_test_ext().execute_with(|| {
+		let role_name = role_name(b"Test");
+		let per_account = RateLimit { max_calls: 2, period: 10, scope: RateLimitScope::PerAccount };
+		let global = RateLimit { max_calls: 2, period: 10, scope: RateLimitScope::Global };
+		assert_ok!(Roles::create_role(
+			root(),
+			role_name.clone(),
+			false,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::add_call(root(), role_name.clone(), remark_call()));
+		assert_ok!(Roles::assign_role(root(), BOB, role_name.clone()));
+		assert_ok!(Roles::set_role_rate_limit(root(), role_name.clone(), Some(per_account)));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			remark_call(),
+			role_name.clone()
+		));
+		assert!(Roles::account_role_usage(&role_name, BOB).is_some());
+		assert_ok!(Roles::set_role_rate_limit(root(), role_name.clone(), Some(global)));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			remark_call(),
+			role_name.clone()
+		));
+		assert!(Roles::role_usage(&role_name).is_some());
+
+		assert_ok!(Roles::unassign_role(root(), BOB, role_name.clone()));
+		assert!(Roles::account_role_usage(&role_name, BOB).is_none());
+		assert_ok!(Roles::remove_call(root(), role_name.clone(), remark_call()));
+		assert_ok!(Roles::remove_role(root(), role_name.clone()));
+
+		assert!(Roles::role_usage(&role_name).is_none());
+	});
+}
+
+#[test]
+fn migration_to_v1_should_translate_roles() {
+	new_test_ext().execute_with(|| {
+		let impersonator = role_name(b"Impersonator");
+		let old_info = OldRoleInfo::<AccountId> {
+			consumers_counter: 2,
+			runtime_version: System::runtime_version().encode().twox_128(),
+			dispatch_origin: crate::RoleDispatchOrigin::SignedAs { who: BOB },
+			allow_filter_bypassing: false,
+		};
+		unhashed::put(&crate::Roles::<Test>::hashed_key_for(&impersonator), &old_info);
+		StorageVersion::new(0).put::<Roles>();
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Roles::on_chain_storage_version(), StorageVersion::new(1));
+		let role_info = Roles::roles(&impersonator).expect("Expected the role to be migrated");
+		assert_eq!(role_info.consent(), ImpersonationConsent::Pending);
+		assert!(role_info.rate_limit().is_none());
+		assert!(!role_info.is_suspended());
+		assert!(role_info.check_version(System::runtime_version()).is_ok());
+		assert_eq!(role_info.check_if_unused(), Err(DispatchError::ConsumerRemaining));
+	});
+}
+
+#[test]
+fn migration_to_v2_should_index_roles() {
+	new_test_ext().execute_with(|| {
+		let group_name = remarker_group();
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		let _ = crate::RoleHolders::<Test>::clear(u32::MAX, None);
+		let _ = crate::RoleGroups::<Test>::clear(u32::MAX, None);
+		let _ = crate::RoleChildren::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(1).put::<Roles>();
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Roles::on_chain_storage_version(), StorageVersion::new(2));
+		assert!(crate::RoleHolders::<Test>::contains_key(remarker_role(), ALICE));
+		assert!(crate::RoleHolders::<Test>::contains_key(balancer_role(), ALICE));
+		assert!(!crate::RoleHolders::<Test>::contains_key(remarker_role(), BOB));
+		assert!(crate::RoleGroups::<Test>::contains_key(remarker_role(), group_name));
+		assert!(Roles::role_children(remarker_role()).unwrap().contains(&default_empty_role()));
+	});
+}
+
+#[test]
+fn set_role_budget_should_work() {
+	new_test_ext().execute_with(|| {
+		let budget = SpendingBudget { limit: 100, period: 10 };
+
+		assert_ok!(Roles::set_role_budget(root(), remarker_role(), Some(budget.clone())));
+		System::assert_last_event(
+			RolesEvent::RoleBudgetSet { role_name: remarker_role(), budget: Some(budget.clone()) }
+				.into(),
+		);
+		assert_eq!(Roles::role_budget(remarker_role()), Some(budget));
+
+		assert_ok!(Roles::set_role_budget(root(), remarker_role(), None));
+		assert_eq!(Roles::role_budget(remarker_role()), None);
+	});
+}
+
+#[test]
+fn invalid_budget_should_prevent_set_role_budget() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::set_role_budget(
+				root(),
+				remarker_role(),
+				Some(SpendingBudget { limit: 100, period: 0 })
+			),
+			RolesError::InvalidBudget
+		);
+		assert_noop!(
+			Roles::set_role_budget(root(), role_name(b"NoRole"), None),
+			RolesError::RoleDoesNotExist
+		);
+		assert_noop!(
+			Roles::set_role_budget(signed_as(ALICE), remarker_role(), None),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn budget_should_prevent_dispatch_call_with_role() {
+	new_test_ext().execute_with(|| {
+		let role_name = treasury_role_with_budget(SpendingBudget { limit: 100, period: 10 });
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_with(60),
+			role_name.clone()
+		));
+		assert_noop!(
+			Roles::dispatch_call_with_role(
+				signed_as(BOB),
+				force_set_balance_call_with(60),
+				role_name.clone()
+			),
+			RolesError::BudgetExceeded
+		);
+		assert_eq!(Balances::free_balance(ALICE), 60);
+		assert_eq!(
+			Roles::role_spending(&role_name),
+			Some(SpendingWindow { window_start: 0, spent: 60 })
+		);
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_with(40),
+			role_name.clone()
+		));
+		assert_eq!(
+			Roles::role_spending(&role_name),
+			Some(SpendingWindow { window_start: 0, spent: 100 })
+		);
+
+		System::set_block_number(10);
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_with(60),
+			role_name.clone()
+		));
+		assert_eq!(
+			Roles::role_spending(&role_name),
+			Some(SpendingWindow { window_start: 10, spent: 60 })
+		);
+	});
+}
+
+#[test]
+fn budget_should_drop_approved_call() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+		let budget = SpendingBudget { limit: 50, period: 10 };
+		assert_ok!(Roles::set_role_budget(root(), role_name.clone(), Some(budget)));
+		let call_hash = sp_core::H256(force_set_balance_call_with(60).blake2_256());
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_with(60),
+			role_name.clone()
+		));
+
+		assert_ok!(Roles::approve_call(
+			signed_as(CHARLIE),
+			role_name.clone(),
+			call_hash,
+			Weight::MAX
+		));
+		System::assert_has_event(
+			RolesEvent::RoleBudgetExhausted { role_name: role_name.clone(), window_start: 0 }
+				.into(),
+		);
+		System::assert_last_event(
+			RolesEvent::ProposedCallDispatched {
+				call_hash,
+				role_name: role_name.clone(),
+				who: BOB,
+				result: Err(RolesError::BudgetExceeded.into()),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::proposal(&role_name, call_hash), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(ALICE), EXISTENTIAL_DEPOSIT + 1);
+		assert_eq!(Roles::role_spending(&role_name), None);
+	});
+}
+
+#[test]
+fn calls_not_moving_funds_should_not_use_budget() {
+	new_test_ext().execute_with(|| {
+		let role_name = treasury_role_with_budget(SpendingBudget { limit: 0, period: 10 });
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_with(0),
+			role_name.clone()
+		));
+		assert_eq!(Roles::role_spending(&role_name), None);
+	});
+}
+
+#[test]
+fn remove_role_should_clear_budget() {
+	new_test_ext().execute_with(|| {
+		let role_name = role_name(b"Test");
+		let budget = SpendingBudget { limit: 100, period: 10 };
+		assert_ok!(Roles::create_role(
+			root(),
+			role_name.clone(),
+			false,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::set_role_budget(root(), role_name.clone(), Some(budget)));
+
+		assert_ok!(Roles::remove_role(root(), role_name.clone()));
+		assert_eq!(Roles::role_budget(role_name), None);
+	});
+}
+
+#[test]
+fn add_role_denial_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_role_denial(root(), remarker_role(), remark_call()));
+		System::assert_last_event(
+			RolesEvent::CallDeniedToRole {
+				role_name: remarker_role(),
+				call_metadata: remark_metadata(),
+			}
+			.into(),
+		);
+		assert!(Roles::call_denied_roles(remark_metadata()).unwrap().contains(&remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		assert_noop!(
+			Roles::add_role_denial(root(), remarker_role(), remark_call()),
+			RolesError::CallAlreadyDenied
+		);
+		assert_noop!(
+			Roles::add_role_denial(signed_as(ALICE), remarker_role(), remark_call()),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_role_denial_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::remove_role_denial(root(), remarker_role(), remark_call()),
+			RolesError::CallNotDenied
+		);
+		assert_ok!(Roles::add_role_denial(root(), remarker_role(), remark_call()));
+
+		assert_ok!(Roles::remove_role_denial(root(), remarker_role(), remark_call()));
+		System::assert_last_event(
+			RolesEvent::CallDenialRemovedFromRole {
+				role_name: remarker_role(),
+				call_metadata: remark_metadata(),
+			}
+			.into(),
+		);
+		assert!(!Roles::call_denied_roles(remark_metadata()).unwrap().contains(&remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 2);
+	});
+}
+
+#[test]
+fn account_denial_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_account_denial(root(), ALICE, remark_call()));
+		System::assert_last_event(
+			RolesEvent::CallDeniedToAccount { who: ALICE, call_metadata: remark_metadata() }.into(),
+		);
+		assert_noop!(
+			Roles::add_account_denial(root(), ALICE, remark_call()),
+			RolesError::CallAlreadyDenied
+		);
+
+		assert_ok!(Roles::remove_account_denial(root(), ALICE, remark_call()));
+		System::assert_last_event(
+			RolesEvent::CallDenialRemovedFromAccount {
+				who: ALICE,
+				call_metadata: remark_metadata(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			Roles::remove_account_denial(root(), ALICE, remark_call()),
+			RolesError::CallNotDenied
+		);
+	});
+}
+
+#[test]
+fn account_denial_should_prevent_dispatch_call_with_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_account_denial(root(), ALICE, remark_call()));
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::CallDenied
+		);
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+
+		assert_ok!(Roles::remove_account_denial(root(), ALICE, remark_call()));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+	});
+}
+
+#[test]
+fn role_denial_should_override_pallet_wildcard() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_pallet_wildcard(
+			root(),
+			default_empty_role(),
+			balances_pallet_index()
+		));
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+		assert_ok!(Roles::add_role_denial(root(), default_empty_role(), force_set_balance_call()));
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(
+				signed_as(BOB),
+				force_set_balance_call(),
+				default_empty_role()
+			),
+			RolesError::CallDenied
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &force_set_balance_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+		assert_ok!(check_role_pre_dispatch(&BOB, &deprecated_call()));
+	});
+}
+
+#[test]
+fn role_denial_should_apply_to_open_calls_and_child_roles() {
+	new_test_ext().execute_with(|| {
+		let open_call = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		assert_ok!(Roles::add_role_denial(root(), default_empty_role(), open_call.clone().into()));
+		assert_ok!(Roles::add_parent_role(root(), remarker_role(), default_empty_role()));
+
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &open_call),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+		assert_ok!(check_role_pre_dispatch(&BOB, &open_call));
+	});
+}
+
+#[test]
+fn set_enforcement_mode_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Roles::enforcement_mode(), EnforcementMode::DefaultAllow);
+
+		assert_ok!(Roles::set_enforcement_mode(root(), EnforcementMode::DefaultDeny));
+		System::assert_last_event(
+			RolesEvent::EnforcementModeSet { mode: EnforcementMode::DefaultDeny }.into(),
+		);
+		assert_eq!(Roles::enforcement_mode(), EnforcementMode::DefaultDeny);
+
+		assert_noop!(
+			Roles::set_enforcement_mode(signed_as(ALICE), EnforcementMode::DefaultAllow),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn default_deny_should_prevent_calls_without_roles() {
+	new_test_ext().execute_with(|| {
+		let open_call = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		assert_ok!(check_role_pre_dispatch(&BOB, &open_call));
+
+		assert_ok!(Roles::set_enforcement_mode(root(), EnforcementMode::DefaultDeny));
+
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &open_call),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+	});
+}
+
+#[test]
+fn default_deny_should_not_prevent_own_calls() {
+	new_test_ext().execute_with(|| {
+		let open_call = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		let allow_call = RuntimeCall::Roles(crate::Call::set_enforcement_mode {
+			mode: EnforcementMode::DefaultAllow,
+		});
+		let dispatch_call = RuntimeCall::Roles(crate::Call::dispatch_call_with_role {
+			call: remark_call(),
+			with_role: remarker_role(),
+		});
+		assert_ok!(Roles::set_enforcement_mode(root(), EnforcementMode::DefaultDeny));
+
+		assert_ok!(check_role_pre_dispatch(&BOB, &allow_call));
+		assert_ok!(check_role_pre_dispatch(&ALICE, &dispatch_call));
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &open_call),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+
+		assert_ok!(Roles::set_enforcement_mode(root(), EnforcementMode::DefaultAllow));
+		assert_ok!(check_role_pre_dispatch(&BOB, &open_call));
+	});
+}
+
+#[test]
+fn add_public_call_should_work() {
+	new_test_ext().execute_with(|| {
+		let open_call = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		assert_ok!(Roles::set_enforcement_mode(root(), EnforcementMode::DefaultDeny));
+
+		assert_ok!(Roles::add_public_call(root(), open_call.clone().into()));
+		System::assert_last_event(
+			RolesEvent::PublicCallAdded {
+				call_metadata: open_call.get_call_metadata_indicies().into(),
+			}
+			.into(),
+		);
+		assert_ok!(check_role_pre_dispatch(&BOB, &open_call));
+
+		assert_noop!(
+			Roles::add_public_call(root(), open_call.into()),
+			RolesError::CallAlreadyPublic
+		);
+	});
+}
+
+#[test]
+fn remove_public_call_should_work() {
+	new_test_ext().execute_with(|| {
+		let open_call = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		assert_ok!(Roles::set_enforcement_mode(root(), EnforcementMode::DefaultDeny));
+		assert_noop!(
+			Roles::remove_public_call(root(), open_call.clone().into()),
+			RolesError::CallNotPublic
+		);
+		assert_ok!(Roles::add_public_call(root(), open_call.clone().into()));
+
+		assert_ok!(Roles::remove_public_call(root(), open_call.clone().into()));
+		System::assert_last_event(
+			RolesEvent::PublicCallRemoved {
+				call_metadata: open_call.get_call_metadata_indicies().into(),
+			}
+			.into(),
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &open_call),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn public_call_should_not_require_roles_but_respect_denials() {
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+
+		assert_ok!(Roles::add_public_call(root(), remark_call()));
+
+		assert_ok!(check_role_pre_dispatch(&BOB, &remark_call()));
+
+		assert_ok!(Roles::add_account_denial(root(), BOB, remark_call()));
+
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+	});
+}
+
+#[test]
+fn add_exclusive_roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+		System::assert_last_event(
+			RolesEvent::ExclusiveRolesAdded {
+				role_name: remarker_role(),
+				other_role_name: default_empty_role(),
+			}
+			.into(),
+		);
+		assert!(Roles::exclusive_roles(remarker_role()).unwrap().contains(&default_empty_role()));
+		assert!(Roles::exclusive_roles(default_empty_role()).unwrap().contains(&remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+		assert_consumers_counter_eq(&default_empty_role(), 1);
+
+		assert_noop!(
+			Roles::add_exclusive_roles(root(), default_empty_role(), remarker_role()),
+			RolesError::RolesAlreadyExclusive
+		);
+	});
+}
+
+#[test]
+fn invalid_roles_should_prevent_add_exclusive_roles() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::add_exclusive_roles(root(), remarker_role(), remarker_role()),
+			RolesError::RoleExclusiveWithItself
+		);
+		assert_noop!(
+			Roles::add_exclusive_roles(root(), remarker_role(), role_name(b"NoRole")),
+			RolesError::RoleDoesNotExist
+		);
+		assert_noop!(
+			Roles::add_exclusive_roles(signed_as(ALICE), remarker_role(), default_empty_role()),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_exclusive_roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::remove_exclusive_roles(root(), remarker_role(), default_empty_role()),
+			RolesError::RolesNotExclusive
+		);
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+
+		assert_ok!(Roles::remove_exclusive_roles(root(), default_empty_role(), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::ExclusiveRolesRemoved {
+				role_name: default_empty_role(),
+				other_role_name: remarker_role(),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::exclusive_roles(remarker_role()), None);
+		assert_eq!(Roles::exclusive_roles(default_empty_role()), None);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+		assert_ok!(Roles::assign_role(root(), ALICE, default_empty_role()));
+	});
+}
+
+#[test]
+fn exclusive_roles_should_prevent_assign_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+
+		assert_noop!(
+			Roles::assign_role(root(), ALICE, default_empty_role()),
+			RolesError::ExclusiveRoleHeld
+		);
+		assert_noop!(
+			Roles::assign_role_with_validity(root(), ALICE, default_empty_role(), None, Some(10)),
+			RolesError::ExclusiveRoleHeld
+		);
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+	});
+}
+
+#[test]
+fn exclusive_ancestor_roles_should_prevent_assign_role() {
+	new_test_ext().execute_with(|| {
+		let role_name = role_name(b"SeniorRemarker");
+		assert_ok!(Roles::create_role(
+			root(),
+			role_name.clone(),
+			false,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::add_parent_role(root(), role_name.clone(), remarker_role()));
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+
+		assert_noop!(Roles::assign_role(root(), BOB, role_name), RolesError::ExclusiveRoleHeld);
+	});
+}
+
+#[test]
+fn exclusive_roles_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		let role_name = role_name(b"SeniorRemarker");
+		assert_ok!(Roles::create_role(
+			root(),
+			role_name.clone(),
+			false,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::add_exclusive_roles(root(), balancer_role(), default_empty_role()));
+
+		assert_noop!(
+			Roles::add_parent_role(root(), remarker_role(), default_empty_role()),
+			RolesError::ExclusiveRoleHeld
+		);
+
+		assert_ok!(Roles::unassign_role(root(), ALICE, remarker_role()));
+		assert_ok!(Roles::add_parent_role(root(), role_name.clone(), remarker_role()));
+		assert_ok!(Roles::assign_role(root(), ALICE, role_name));
+		assert_noop!(
+			Roles::add_parent_role(root(), remarker_role(), default_empty_role()),
+			RolesError::ExclusiveRoleHeld
+		);
+
+		assert_ok!(Roles::unassign_role(root(), ALICE, balancer_role()));
+		assert_ok!(Roles::add_parent_role(root(), remarker_role(), default_empty_role()));
+	});
+}
+
+#[test]
+fn too_many_holders_should_prevent_add_parent_role() {
+	new_test_ext().execute_with(|| {
+		let auditor = role_name(b"Auditor");
+		assert_ok!(Roles::create_role(
+			root(),
+			auditor.clone(),
+			false,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::add_exclusive_roles(root(), default_empty_role(), auditor));
+		let group_name = remarker_group();
+		assert_ok!(Roles::assign_role(root(), 4, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), 5, remarker_role()));
+		assert!(crate::RoleHolders::<Test>::contains_key(remarker_role(), 4));
+		assert!(crate::RoleGroups::<Test>::contains_key(remarker_role(), &group_name));
+
+		assert_noop!(
+			Roles::add_parent_role(root(), remarker_role(), default_empty_role()),
+			RolesError::TooManyRoleHolders
+		);
+
+		assert_ok!(Roles::unassign_role(root(), 4, remarker_role()));
+		assert!(!crate::RoleHolders::<Test>::contains_key(remarker_role(), 4));
+		assert_ok!(Roles::add_parent_role(root(), remarker_role(), default_empty_role()));
+
+		assert_ok!(Roles::unassign_role_from_group(root(), group_name.clone(), remarker_role()));
+		assert!(!crate::RoleGroups::<Test>::contains_key(remarker_role(), group_name));
+	});
+}
+
+#[test]
+fn exclusive_role_violations_should_report_existing_assignments() {
+	new_test_ext().execute_with(|| {
+		assert!(Roles::exclusive_role_violations().is_empty());
+
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), balancer_role()));
+
+		assert_eq!(
+			Roles::exclusive_role_violations(),
+			vec![(ALICE, balancer_role(), remarker_role())]
+		);
+		assert!(Roles::account_exclusive_role_violations(&BOB).is_empty());
+	});
+}
+
+#[test]
+#[should_panic(expected = "Expected no exclusive roles held by an account during genesis build")]
+fn exclusive_roles_should_prevent_genesis_assignments() {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	crate::GenesisConfig::<Test> {
+		roles: vec![(remarker_role(), false, false), (balancer_role(), false, false)],
+		calls: vec![],
+		users: vec![(remarker_role(), ALICE), (balancer_role(), ALICE)],
+		parents: vec![],
+		public_calls: vec![],
+		exclusions: vec![(remarker_role(), balancer_role())],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
+
+#[test]
+fn set_approval_threshold_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_approval_threshold(
+			root(),
+			balancer_role(),
+			force_set_balance_call(),
+			Some(2)
+		));
+		System::assert_last_event(
+			RolesEvent::ApprovalThresholdSet {
+				role_name: balancer_role(),
+				call_metadata: force_set_balance_metadata(),
+				threshold: Some(2),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Roles::approval_threshold(force_set_balance_metadata(), balancer_role()),
+			Some(2)
+		);
+
+		assert_ok!(Roles::set_approval_threshold(
+			root(),
+			balancer_role(),
+			force_set_balance_call(),
+			None
+		));
+		assert_eq!(Roles::approval_threshold(force_set_balance_metadata(), balancer_role()), None);
+	});
+}
+
+#[test]
+fn invalid_threshold_should_prevent_set_approval_threshold() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::set_approval_threshold(
+				root(),
+				balancer_role(),
+				force_set_balance_call(),
+				Some(1)
+			),
+			RolesError::InvalidApprovalThreshold
+		);
+		assert_noop!(
+			Roles::set_approval_threshold(
+				root(),
+				balancer_role(),
+				force_set_balance_call(),
+				Some(6)
+			),
+			RolesError::InvalidApprovalThreshold
+		);
+		assert_noop!(
+			Roles::set_approval_threshold(root(), balancer_role(), remark_call(), Some(2)),
+			RolesError::CallNotAttachedToRole
+		);
+		assert_noop!(
+			Roles::set_approval_threshold(
+				signed_as(ALICE),
+				balancer_role(),
+				force_set_balance_call(),
+				Some(2)
+			),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn approval_threshold_should_prevent_direct_dispatch() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), force_set_balance_call(), role_name),
+			RolesError::ApprovalRequired
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &force_set_balance_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn approved_call_should_be_dispatched() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+		System::assert_last_event(
+			RolesEvent::CallProposed {
+				role_name: role_name.clone(),
+				who: BOB,
+				call_hash: force_set_balance_hash(),
+				call_metadata: force_set_balance_metadata(),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+		assert_eq!(Balances::free_balance(ALICE), EXISTENTIAL_DEPOSIT + 1);
+
+		assert_ok!(Roles::approve_call(
+			signed_as(CHARLIE),
+			role_name.clone(),
+			force_set_balance_hash(),
+			Weight::MAX
+		));
+		System::assert_has_event(
+			RolesEvent::CallApproved {
+				role_name: role_name.clone(),
+				call_hash: force_set_balance_hash(),
+				who: CHARLIE,
+				approvals: 2,
+			}
+			.into(),
+		);
+		System::assert_last_event(
+			RolesEvent::ProposedCallDispatched {
+				call_hash: force_set_balance_hash(),
+				role_name: role_name.clone(),
+				who: BOB,
+				result: Ok(()),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::proposal(&role_name, force_set_balance_hash()), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+		assert_eq!(Balances::free_balance(ALICE), 0);
+	});
+}
+
+#[test]
+fn same_call_should_be_proposed_with_different_roles() {
+	new_test_ext().execute_with(|| {
+		let other_role_name = role_name(b"Council");
+		let role_name = approval_role_with_holders();
+		assert_ok!(Roles::create_role(
+			root(),
+			other_role_name.clone(),
+			false,
+			crate::RoleDispatchOrigin::Root
+		));
+		assert_ok!(Roles::add_call(root(), other_role_name.clone(), force_set_balance_call()));
+		assert_ok!(Roles::set_approval_threshold(
+			root(),
+			other_role_name.clone(),
+			force_set_balance_call(),
+			Some(2)
+		));
+		assert_ok!(Roles::assign_role(root(), BOB, other_role_name.clone()));
+
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			other_role_name.clone()
+		));
+
+		assert!(Roles::proposal(&role_name, force_set_balance_hash()).is_some());
+		assert!(Roles::proposal(&other_role_name, force_set_balance_hash()).is_some());
+		assert_ok!(Roles::cancel_proposal(
+			signed_as(BOB),
+			role_name.clone(),
+			force_set_balance_hash()
+		));
+		assert!(Roles::proposal(&other_role_name, force_set_balance_hash()).is_some());
+	});
+}
+
+#[test]
+fn invalid_proposal_should_prevent_propose_call_with_role() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+
+		assert_noop!(
+			Roles::propose_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::ApprovalNotRequired
+		);
+		assert_noop!(
+			Roles::propose_call_with_role(
+				signed_as(ALICE),
+				force_set_balance_call(),
+				role_name.clone()
+			),
+			RolesError::MissingRole
+		);
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+		assert_noop!(
+			Roles::propose_call_with_role(signed_as(CHARLIE), force_set_balance_call(), role_name),
+			RolesError::ProposalAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn invalid_approval_should_prevent_approve_call() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+		assert_noop!(
+			Roles::approve_call(
+				signed_as(CHARLIE),
+				role_name.clone(),
+				force_set_balance_hash(),
+				Weight::MAX
+			),
+			RolesError::ProposalNotFound
+		);
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+
+		assert_noop!(
+			Roles::approve_call(
+				signed_as(ALICE),
+				role_name.clone(),
+				force_set_balance_hash(),
+				Weight::MAX
+			),
+			RolesError::MissingRole
+		);
+		assert_noop!(
+			Roles::approve_call(
+				signed_as(BOB),
+				role_name.clone(),
+				force_set_balance_hash(),
+				Weight::MAX
+			),
+			RolesError::AlreadyApproved
+		);
+		assert_noop!(
+			Roles::approve_call(
+				signed_as(CHARLIE),
+				role_name.clone(),
+				force_set_balance_hash(),
+				Weight::zero()
+			),
+			RolesError::MaxWeightTooLow
+		);
+
+		System::set_block_number(11);
+
+		assert_noop!(
+			Roles::approve_call(
+				signed_as(CHARLIE),
+				role_name.clone(),
+				force_set_balance_hash(),
+				Weight::MAX
+			),
+			RolesError::ProposalExpired
+		);
+	});
+}
+
+#[test]
+fn cancel_proposal_should_work() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+
+		assert_noop!(
+			Roles::cancel_proposal(signed_as(CHARLIE), role_name.clone(), force_set_balance_hash()),
+			RolesError::NotProposer
+		);
+		assert_ok!(Roles::cancel_proposal(
+			signed_as(BOB),
+			role_name.clone(),
+			force_set_balance_hash()
+		));
+		System::assert_last_event(
+			RolesEvent::ProposalCancelled {
+				role_name: role_name.clone(),
+				call_hash: force_set_balance_hash(),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::proposal(&role_name, force_set_balance_hash()), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn remove_expired_proposal_should_work() {
+	new_test_ext().execute_with(|| {
+		let role_name = approval_role_with_holders();
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name.clone()
+		));
+
+		assert_noop!(
+			Roles::remove_expired_proposal(
+				signed_as(ALICE),
+				role_name.clone(),
+				force_set_balance_hash()
+			),
+			RolesError::ProposalNotExpired
+		);
+
+		System::set_block_number(11);
+
+		assert_ok!(Roles::remove_expired_proposal(
+			signed_as(ALICE),
+			role_name.clone(),
+			force_set_balance_hash()
+		));
+		System::assert_last_event(
+			RolesEvent::ProposalTimedOut { role_name, call_hash: force_set_balance_hash() }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn signed_as_role_should_require_consent() {
+	new_test_ext().execute_with(|| {
+		let role_name = impersonating_role(CHARLIE);
+		assert_eq!(Roles::roles(&role_name).unwrap().consent(), ImpersonationConsent::Pending);
+		assert_eq!(
+			Roles::roles(remarker_role()).unwrap().consent(),
+			ImpersonationConsent::NotRequired
+		);
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), role_name.clone()),
+			RolesError::ImpersonationNotConsented
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn accept_impersonation_should_work() {
+	new_test_ext().execute_with(|| {
+		let role_name = impersonating_role(CHARLIE);
+
+		assert_ok!(Roles::accept_impersonation(signed_as(CHARLIE), role_name.clone()));
+		System::assert_last_event(
+			RolesEvent::ImpersonationAccepted { role_name: role_name.clone(), who: CHARLIE }.into(),
+		);
+		assert_eq!(Roles::roles(&role_name).unwrap().consent(), ImpersonationConsent::Given);
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			remark_call(),
+			role_name.clone()
+		));
+		System::assert_has_event(
+			SystemEvent::Remarked { sender: CHARLIE, hash: sp_core::H256(b"abc".blake2_256()) }
+				.into(),
+		);
+		assert_noop!(
+			Roles::accept_impersonation(signed_as(CHARLIE), role_name),
+			RolesError::ConsentAlreadyGiven
+		);
+	});
+}
+
+#[test]
+fn accept_impersonation_should_fail_while_role_is_renamed() {
+	new_test_ext().execute_with(|| {
+		let impersonator = impersonating_role(CHARLIE);
+		let proxy = role_name(b"Proxy");
+		assert_ok!(Roles::assign_role(root(), ALICE, impersonator.clone()));
+		assert_ok!(Roles::assign_role(root(), 4, impersonator.clone()));
+		assert_ok!(Roles::rename_role(root(), impersonator.clone(), proxy.clone()));
+		assert!(Roles::role_rename(&impersonator).is_some());
+
+		assert_noop!(
+			Roles::accept_impersonation(signed_as(CHARLIE), impersonator.clone()),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::accept_impersonation(signed_as(CHARLIE), proxy),
+			RolesError::RoleBeingRenamed
+		);
+		assert_eq!(Roles::roles(&impersonator).unwrap().consent(), ImpersonationConsent::Pending);
+	});
+}
+
+#[test]
+fn revoke_impersonation_should_work() {
+	new_test_ext().execute_with(|| {
+		let role_name = impersonating_role(CHARLIE);
+		assert_ok!(Roles::accept_impersonation(signed_as(CHARLIE), role_name.clone()));
+
+		assert_ok!(Roles::revoke_impersonation(signed_as(CHARLIE), role_name.clone()));
+		System::assert_last_event(
+			RolesEvent::ImpersonationRevoked { role_name: role_name.clone(), who: CHARLIE }.into(),
+		);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), role_name.clone()),
+			RolesError::ImpersonationNotConsented
+		);
+		assert_noop!(
+			Roles::revoke_impersonation(signed_as(CHARLIE), role_name.clone()),
+			RolesError::ConsentAlreadyRevoked
+		);
+
+		assert_ok!(Roles::accept_impersonation(signed_as(CHARLIE), role_name.clone()));
+
+		assert_ok!(Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), role_name));
+	});
+}
+
+#[test]
+fn revoke_impersonation_should_fail_while_role_is_renamed() {
+	new_test_ext().execute_with(|| {
+		let impersonator = impersonating_role(CHARLIE);
+		let proxy = role_name(b"Proxy");
+		assert_ok!(Roles::assign_role(root(), ALICE, impersonator.clone()));
+		assert_ok!(Roles::assign_role(root(), 4, impersonator.clone()));
+		assert_ok!(Roles::accept_impersonation(signed_as(CHARLIE), impersonator.clone()));
+		assert_ok!(Roles::rename_role(root(), impersonator.clone(), proxy.clone()));
+		assert!(Roles::role_rename(&impersonator).is_some());
+
+		assert_noop!(
+			Roles::revoke_impersonation(signed_as(CHARLIE), impersonator.clone()),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::revoke_impersonation(signed_as(CHARLIE), proxy),
+			RolesError::RoleBeingRenamed
+		);
+		assert_eq!(Roles::roles(&impersonator).unwrap().consent(), ImpersonationConsent::Given);
+	});
+}
+
+#[test]
+fn only_impersonated_account_should_give_consent() {
+	new_test_ext().execute_with(|| {
+		let impersonator = impersonating_role(CHARLIE);
+
+		assert_noop!(
+			Roles::accept_impersonation(signed_as(BOB), impersonator.clone()),
+			RolesError::NotImpersonatedAccount
+		);
+		assert_noop!(
+			Roles::revoke_impersonation(signed_as(BOB), impersonator),
+			RolesError::NotImpersonatedAccount
+		);
+		assert_noop!(
+			Roles::accept_impersonation(signed_as(ALICE), remarker_role()),
+			RolesError::NotImpersonatedAccount
+		);
+		assert_noop!(
+			Roles::accept_impersonation(signed_as(ALICE), role_name(b"NoRole")),
+			RolesError::RoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn create_group_should_work() {
+	new_test_ext().execute_with(|| {
+		let group_name = role_name(b"Team");
+		assert_ok!(Roles::create_group(root(), group_name.clone()));
+		System::assert_last_event(
+			RolesEvent::GroupCreated { group_name: group_name.clone() }.into(),
+		);
+		assert!(Roles::group_members(&group_name).unwrap().is_empty());
+
+		assert_noop!(Roles::create_group(root(), group_name.clone()), RolesError::GroupExists);
+		assert_noop!(
+			Roles::create_group(signed_as(ALICE), role_name(b"Other")),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_group_member_should_work() {
+	new_test_ext().execute_with(|| {
+		let group_name = role_name(b"Team");
+		assert_ok!(Roles::create_group(root(), group_name.clone()));
+
+		assert_ok!(Roles::add_group_member(root(), group_name.clone(), BOB));
+		System::assert_last_event(
+			RolesEvent::AccountAddedToGroup { group_name: group_name.clone(), who: BOB }.into(),
+		);
+		assert!(Roles::group_members(&group_name).unwrap().contains(&BOB));
+		assert!(Roles::account_groups(BOB).unwrap().contains(&group_name));
+
+		assert_noop!(
+			Roles::add_group_member(root(), group_name, BOB),
+			RolesError::AlreadyGroupMember
+		);
+		assert_noop!(
+			Roles::add_group_member(root(), role_name(b"NoGroup"), BOB),
+			RolesError::GroupDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn group_members_should_hold_group_roles() {
+	new_test_ext().execute_with(|| {
+		let group_name = remarker_group();
+		System::assert_last_event(
+			RolesEvent::GroupAssignedToRole {
+				role_name: remarker_role(),
+				group_name: group_name.clone(),
+			}
+			.into(),
+		);
+		assert!(Roles::holds_role(&BOB, &remarker_role()));
+		assert!(!account_set_contains(&BOB, &remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		assert_ok!(check_role_pre_dispatch(&BOB, &remark_call()));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(CHARLIE),
+			remark_call(),
+			remarker_role()
+		));
+
+		assert_ok!(Roles::remove_group_member(root(), group_name.clone(), BOB));
+		System::assert_last_event(
+			RolesEvent::AccountRemovedFromGroup { group_name: group_name.clone(), who: BOB }.into(),
+		);
+		assert!(Roles::account_groups(BOB).is_none());
+		assert!(!Roles::holds_role(&BOB, &remarker_role()));
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), remarker_role()),
+			RolesError::MissingRole
+		);
+		assert_noop!(
+			Roles::remove_group_member(root(), group_name, BOB),
+			RolesError::NotGroupMember
+		);
+	});
+}
+
+#[test]
+fn unassign_role_from_group_should_work() {
+	new_test_ext().execute_with(|| {
+		let group_name = remarker_group();
+
+		assert_ok!(Roles::unassign_role_from_group(root(), group_name.clone(), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::GroupUnassignedFromRole {
+				role_name: remarker_role(),
+				group_name: group_name.clone(),
+			}
+			.into(),
+		);
+		assert!(Roles::group_roles(&group_name).is_none());
+		assert!(!Roles::holds_role(&CHARLIE, &remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 2);
+
+		assert_noop!(
+			Roles::unassign_role_from_group(root(), group_name.clone(), remarker_role()),
+			RolesError::RoleNotAssignedToGroup
+		);
+		assert_noop!(
+			Roles::assign_role_to_group(signed_as(BOB), group_name, remarker_role()),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn remove_group_should_work() {
+	new_test_ext().execute_with(|| {
+		let group_name = remarker_group();
+
+		assert_noop!(Roles::remove_group(root(), group_name.clone()), RolesError::GroupNotEmpty);
+		assert_ok!(Roles::unassign_role_from_group(root(), group_name.clone(), remarker_role()));
+		assert_noop!(Roles::remove_group(root(), group_name.clone()), RolesError::GroupNotEmpty);
+		assert_ok!(Roles::remove_group_member(root(), group_name.clone(), BOB));
+		assert_ok!(Roles::remove_group_member(root(), group_name.clone(), CHARLIE));
+
+		assert_ok!(Roles::remove_group(root(), group_name.clone()));
+		System::assert_last_event(
+			RolesEvent::GroupRemoved { group_name: group_name.clone() }.into(),
+		);
+		assert!(Roles::group_members(&group_name).is_none());
+		assert_noop!(Roles::remove_group(root(), group_name), RolesError::GroupDoesNotExist);
+	});
+}
+
+#[test]
+fn group_roles_should_respect_exclusive_roles() {
+	new_test_ext().execute_with(|| {
+		let group_name = remarker_group();
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+
+		assert_noop!(
+			Roles::assign_role(root(), BOB, default_empty_role()),
+			RolesError::ExclusiveRoleHeld
+		);
+		assert_noop!(
+			Roles::assign_role_to_group(root(), group_name.clone(), default_empty_role()),
+			RolesError::ExclusiveRoleHeld
+		);
+		let other_group_name = role_name(b"Auditors");
+		assert_ok!(Roles::create_group(root(), other_group_name.clone()));
+		assert_ok!(Roles::assign_role_to_group(
+			root(),
+			other_group_name.clone(),
+			default_empty_role()
+		));
+		assert_noop!(
+			Roles::add_group_member(root(), other_group_name, ALICE),
+			RolesError::ExclusiveRoleHeld
+		);
+	});
+}
+
+#[test]
+fn assign_role_with_scope_should_work() {
+	new_test_ext().execute_with(|| {
+		let role_name = scoped_balancer_role(CHARLIE);
+		System::assert_last_event(
+			RolesEvent::AssignmentScopeSet {
+				role_name: role_name.clone(),
+				who: BOB,
+				scope: CHARLIE,
+			}
+			.into(),
+		);
+		assert!(account_set_contains(&BOB, &role_name));
+		assert_eq!(Roles::assignment_scopes(BOB, &role_name), scope_set_with(vec![CHARLIE]));
+
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_for(CHARLIE),
+			role_name.clone()
+		));
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), force_set_balance_call(), role_name),
+			RolesError::ResourceOutOfScope
+		);
+	});
+}
+
+#[test]
+fn assign_role_with_scope_should_add_scopes() {
+	new_test_ext().execute_with(|| {
+		let role_name = scoped_balancer_role(CHARLIE);
+
+		assert_ok!(Roles::assign_role_with_scope(root(), BOB, role_name.clone(), ALICE));
+		assert_eq!(Roles::assignment_scopes(BOB, &role_name), scope_set_with(vec![ALICE, CHARLIE]));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_for(ALICE),
+			role_name.clone()
+		));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call_for(CHARLIE),
+			role_name.clone()
+		));
+
+		assert_noop!(
+			Roles::assign_role_with_scope(root(), BOB, role_name.clone(), ALICE),
+			RolesError::ScopeAlreadyAssigned
+		);
+		assert_noop!(
+			Roles::assign_role_with_scope(root(), BOB, role_name, BOB),
+			RolesError::TooManyScopes
+		);
+		assert_noop!(
+			Roles::assign_role_with_scope(root(), ALICE, remarker_role(), CHARLIE),
+			RolesError::RoleAlreadyAssigned
+		);
+	});
+}
+
+#[test]
+fn scoped_admin_role_holder_should_not_manage_role() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), Some(remarker_role())));
+		assert_ok!(Roles::assign_role_with_scope(root(), BOB, remarker_role(), CHARLIE));
+
+		assert_noop!(
+			Roles::assign_role(signed_as(BOB), CHARLIE, default_empty_role()),
+			RolesError::ResourceOutOfScope
+		);
+		assert_noop!(
+			Roles::add_call(signed_as(BOB), default_empty_role(), remark_call()),
+			RolesError::ResourceOutOfScope
+		);
+	});
+}
+
+#[test]
+fn scoped_assignment_should_only_validate_calls_on_its_resource() {
+	new_test_ext().execute_with(|| {
+		scoped_balancer_role(CHARLIE);
+
+		assert_ok!(check_role_pre_dispatch(&BOB, &force_set_balance_call_for(CHARLIE)));
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &force_set_balance_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+		assert_err!(
+			Roles::validate_by_metadata(force_set_balance_metadata(), &BOB),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+	});
+}
+
+#[test]
+fn unassign_role_should_remove_assignment_scope() {
+	new_test_ext().execute_with(|| {
+		let role_name = scoped_balancer_role(CHARLIE);
+
+		assert_ok!(Roles::unassign_role(root(), BOB, role_name.clone()));
+		assert_eq!(Roles::assignment_scopes(BOB, &role_name), None);
+
+		assert_ok!(Roles::assign_role(root(), BOB, role_name.clone()));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			role_name
+		));
+	});
+}
+
+#[test]
+fn set_role_membership_rule_should_work() {
+	new_test_ext().execute_with(|| {
+		let whale = whale_role(1_000);
+		System::assert_last_event(
+			RolesEvent::RoleMembershipRuleSet {
+				role_name: whale.clone(),
+				rule: Some(MockMembershipRule::MinFreeBalance(1_000)),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Roles::role_membership_rules().get(&whale),
+			Some(&MockMembershipRule::MinFreeBalance(1_000))
+		);
+
+		assert_ok!(Roles::set_role_membership_rule(root(), whale.clone(), None));
+		assert!(Roles::role_membership_rules().is_empty());
+		assert_noop!(
+			Roles::set_role_membership_rule(
+				signed_as(ALICE),
+				whale,
+				Some(MockMembershipRule::MinFreeBalance(1_000))
+			),
+			RolesError::BadOrigin
+		);
+		assert_noop!(
+			Roles::set_role_membership_rule(root(), role_name(b"NoRole"), None),
+			RolesError::RoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn set_role_membership_rule_should_fail_while_role_is_renamed() {
+	new_test_ext().execute_with(|| {
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), CHARLIE, remarker_role()));
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		assert!(Roles::role_rename(remarker_role()).is_some());
+
+		assert_noop!(
+			Roles::set_role_membership_rule(
+				root(),
+				remarker_role(),
+				Some(MockMembershipRule::MinFreeBalance(1_000))
+			),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::set_role_membership_rule(
+				root(),
+				scribe,
+				Some(MockMembershipRule::MinFreeBalance(1_000))
+			),
+			RolesError::RoleBeingRenamed
+		);
+	});
+}
+
+#[test]
+fn accounts_satisfying_membership_rule_should_hold_role() {
+	new_test_ext().execute_with(|| {
+		let role_name = whale_role(1_000);
+		assert!(!Roles::holds_role(&BOB, &role_name));
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), role_name.clone()),
+			RolesError::MissingRole
+		);
+
+		Balances::make_free_balance_be(&BOB, 1_000);
+
+		assert!(Roles::holds_role(&BOB, &role_name));
+		assert!(!account_set_contains(&BOB, &role_name));
+		assert_ok!(Roles::validate_by_metadata(remark_metadata(), &BOB));
+		assert_ok!(Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), role_name));
+	});
+}
+
+#[test]
+fn remove_role_should_remove_membership_rule() {
+	new_test_ext().execute_with(|| {
+		let role_name = whale_role(1_000);
+		assert_ok!(Roles::remove_call(root(), role_name.clone(), remark_call()));
+
+		assert_ok!(Roles::remove_role(root(), role_name.clone()));
+		assert!(Roles::role_membership_rules().get(&role_name).is_none());
+	});
+}
+
+#[test]
+fn membership_rule_should_not_bypass_exclusive_roles() {
+	new_test_ext().execute_with(|| {
+		let whale = whale_role(1_000);
+		assert_noop!(
+			Roles::add_exclusive_roles(root(), whale.clone(), remarker_role()),
+			RolesError::RuleBasedExclusiveRole
+		);
+
+		assert_ok!(Roles::add_exclusive_roles(root(), default_empty_role(), remarker_role()));
+		assert_noop!(
+			Roles::set_role_membership_rule(
+				root(),
+				default_empty_role(),
+				Some(MockMembershipRule::MinFreeBalance(1))
+			),
+			RolesError::RuleBasedExclusiveRole
+		);
+		assert_noop!(
+			Roles::add_parent_role(root(), whale, default_empty_role()),
+			RolesError::RuleBasedExclusiveRole
+		);
+		assert_ok!(Roles::set_role_membership_rule(root(), default_empty_role(), None));
+	});
+}
+
+#[test]
+fn invite_to_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::invite_to_role(root(), BOB, remarker_role()));
+		System::assert_last_event(
+			RolesEvent::RoleInvitationSent { role_name: remarker_role(), who: BOB, expires_at: 11 }
+				.into(),
+		);
+		assert_eq!(Roles::invitation(BOB, remarker_role()), Some(11));
+		assert!(!account_set_contains(&BOB, &remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		assert_noop!(
+			Roles::invite_to_role(root(), BOB, remarker_role()),
+			RolesError::InvitationAlreadyExists
+		);
+		assert_noop!(
+			Roles::invite_to_role(root(), ALICE, remarker_role()),
+			RolesError::RoleAlreadyAssigned
+		);
+		assert_noop!(
+			Roles::invite_to_role(signed_as(ALICE), CHARLIE, remarker_role()),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn accept_role_invitation_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::invite_to_role(root(), BOB, remarker_role()));
+
+		assert_ok!(Roles::accept_role_invitation(signed_as(BOB), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::RoleInvitationAccepted { role_name: remarker_role(), who: BOB }.into(),
+		);
+		System::assert_has_event(
+			RolesEvent::AccountAssignedToRole { role_name: remarker_role(), who: BOB }.into(),
+		);
+		assert!(account_set_contains(&BOB, &remarker_role()));
+		assert_eq!(Roles::invitation(BOB, remarker_role()), None);
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		assert_noop!(
+			Roles::accept_role_invitation(signed_as(BOB), remarker_role()),
+			RolesError::InvitationNotFound
+		);
+	});
+}
+
+#[test]
+fn expired_invitation_should_not_be_accepted() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::invite_to_role(root(), BOB, remarker_role()));
+		System::set_block_number(11);
+
+		assert_noop!(
+			Roles::accept_role_invitation(signed_as(BOB), remarker_role()),
+			RolesError::InvitationExpired
+		);
+		assert_ok!(Roles::decline_role_invitation(signed_as(BOB), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::RoleInvitationDeclined { role_name: remarker_role(), who: BOB }.into(),
+		);
+		assert_eq!(Roles::invitation(BOB, remarker_role()), None);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+	});
+}
+
+#[test]
+fn cancel_role_invitation_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::invite_to_role(root(), BOB, remarker_role()));
+
+		assert_noop!(
+			Roles::cancel_role_invitation(signed_as(BOB), BOB, remarker_role()),
+			RolesError::BadOrigin
+		);
+		assert_ok!(Roles::cancel_role_invitation(root(), BOB, remarker_role()));
+		System::assert_last_event(
+			RolesEvent::RoleInvitationCancelled { role_name: remarker_role(), who: BOB }.into(),
+		);
+		assert_noop!(
+			Roles::accept_role_invitation(signed_as(BOB), remarker_role()),
+			RolesError::InvitationNotFound
+		);
+		assert_noop!(
+			Roles::decline_role_invitation(signed_as(BOB), remarker_role()),
+			RolesError::InvitationNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_expired_invitation_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::invite_to_role(root(), BOB, remarker_role()));
+		assert_noop!(
+			Roles::remove_expired_invitation(signed_as(CHARLIE), BOB, remarker_role()),
+			RolesError::InvitationNotExpired
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Roles::remove_expired_invitation(signed_as(CHARLIE), BOB, remarker_role()));
+		System::assert_last_event(
+			RolesEvent::RoleInvitationExpired { role_name: remarker_role(), who: BOB }.into(),
+		);
+		assert_eq!(Roles::invitation(BOB, remarker_role()), None);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+		assert_noop!(
+			Roles::remove_expired_invitation(signed_as(CHARLIE), BOB, remarker_role()),
+			RolesError::InvitationNotFound
+		);
+	});
+}
+
+#[test]
+fn renounce_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::renounce_role(signed_as(ALICE), remarker_role()));
+		System::assert_last_event(
+			RolesEvent::AccountUnassignedFromRole { role_name: remarker_role(), who: ALICE }.into(),
+		);
+		assert!(!account_set_contains(&ALICE, &remarker_role()));
+		assert_consumers_counter_eq(&remarker_role(), 1);
+
+		assert_noop!(
+			Roles::renounce_role(signed_as(ALICE), remarker_role()),
+			RolesError::MissingRole
+		);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::MissingRole
+		);
+	});
+}
+
+#[test]
+fn renounce_all_roles_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::renounce_all_roles(signed_as(ALICE)));
+		System::assert_has_event(
+			RolesEvent::AccountUnassignedFromRole { role_name: remarker_role(), who: ALICE }.into(),
+		);
+		System::assert_has_event(
+			RolesEvent::AccountUnassignedFromRole { role_name: balancer_role(), who: ALICE }.into(),
+		);
+		assert!(Roles::account_roles(ALICE).unwrap_or_default().is_empty());
+		assert_consumers_counter_eq(&remarker_role(), 1);
+		assert_consumers_counter_eq(&balancer_role(), 1);
+		assert_consumers_counter_eq(&obsolete_role(), 1);
+
+		assert_noop!(Roles::renounce_all_roles(signed_as(ALICE)), RolesError::MissingRole);
+		assert_noop!(Roles::renounce_all_roles(signed_as(BOB)), RolesError::MissingRole);
+	});
+}
+
+#[test]
+fn suspend_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::suspend_role(root(), remarker_role()));
+		System::assert_last_event(RolesEvent::RoleSuspended { role_name: remarker_role() }.into());
+		assert!(Roles::roles(remarker_role()).unwrap().is_suspended());
+		assert!(account_set_contains(&ALICE, &remarker_role()));
+		assert!(call_set_contains(&remark_metadata(), &remarker_role()));
+
+		assert_err!(
+			Roles::check_role_existance_and_version(&remarker_role()),
+			RolesError::RoleSuspended
+		);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::RoleSuspended
+		);
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(ROLE_SUSPENDED))
+		);
+		assert_noop!(
+			Roles::suspend_role(root(), remarker_role()),
+			RolesError::RoleAlreadySuspended
+		);
+		assert_noop!(Roles::suspend_role(signed_as(ALICE), balancer_role()), RolesError::BadOrigin);
+	});
+}
+
+#[test]
+fn suspend_role_should_fail_while_role_is_renamed() {
+	new_test_ext().execute_with(|| {
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), CHARLIE, remarker_role()));
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		assert!(Roles::role_rename(remarker_role()).is_some());
+
+		assert_noop!(Roles::suspend_role(root(), remarker_role()), RolesError::RoleBeingRenamed);
+		assert_noop!(Roles::suspend_role(root(), scribe), RolesError::RoleBeingRenamed);
+	});
+}
+
+#[test]
+fn resume_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Roles::resume_role(root(), remarker_role()), RolesError::RoleNotSuspended);
+		assert_ok!(Roles::suspend_role(root(), remarker_role()));
+
+		assert_ok!(Roles::resume_role(root(), remarker_role()));
+		System::assert_last_event(RolesEvent::RoleResumed { role_name: remarker_role() }.into());
+		assert!(!Roles::roles(remarker_role()).unwrap().is_suspended());
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert_noop!(
+			Roles::resume_role(root(), role_name(b"NoRole")),
+			RolesError::RoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn resume_role_should_fail_while_role_is_renamed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::suspend_role(root(), remarker_role()));
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), CHARLIE, remarker_role()));
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		assert!(Roles::role_rename(remarker_role()).is_some());
+
+		assert_noop!(Roles::resume_role(root(), remarker_role()), RolesError::RoleBeingRenamed);
+		assert_noop!(Roles::resume_role(root(), scribe), RolesError::RoleBeingRenamed);
+		assert!(Roles::roles(remarker_role()).unwrap().is_suspended());
+	});
+}
+
+#[test]
+fn suspended_role_should_keep_denying_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::add_role_denial(root(), balancer_role(), remark_call()));
+		assert_ok!(Roles::suspend_role(root(), balancer_role()));
+
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(CALL_DENIED))
+		);
+	});
+}
+
+#[test]
+fn suspended_parent_role_should_not_grant_inherited_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role(root(), BOB, default_empty_role()));
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		assert_ok!(Roles::suspend_role(root(), remarker_role()));
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(BOB), remark_call(), default_empty_role()),
+			RolesError::CallNotAttachedToRole
+		);
+		assert_err!(
+			check_role_pre_dispatch(&BOB, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Call)
+		);
+
+		assert_ok!(Roles::resume_role(root(), remarker_role()));
+		assert_ok!(check_role_pre_dispatch(&BOB, &remark_call()));
+	});
+}
+
+#[test]
+fn pause_role_dispatch_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::pause_role_dispatch(root(), false, None));
+		System::assert_last_event(
+			RolesEvent::RoleDispatchPaused { block_extension: false, until: None }.into(),
+		);
+		assert_eq!(Roles::active_pause(), Some(PauseState { block_extension: false, until: None }));
+
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::DispatchPaused
+		);
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+		assert_noop!(
+			Roles::pause_role_dispatch(signed_as(ALICE), true, None),
+			RolesError::BadOrigin
+		);
+		assert_noop!(Roles::unpause_role_dispatch(signed_as(ALICE)), RolesError::BadOrigin);
+
+		assert_ok!(Roles::unpause_role_dispatch(root()));
+		System::assert_last_event(RolesEvent::RoleDispatchUnpaused.into());
+		assert_eq!(Roles::pause_state(), None);
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert_noop!(Roles::unpause_role_dispatch(root()), RolesError::DispatchNotPaused);
+	});
+}
+
+#[test]
+fn pause_role_dispatch_should_block_extension() {
+	new_test_ext().execute_with(|| {
+		let open_call = RuntimeCall::System(SystemCall::remark { remark: vec![] });
+		assert_ok!(Roles::pause_role_dispatch(root(), true, None));
+
+		assert_err!(
+			check_role_pre_dispatch(&ALICE, &remark_call()),
+			TransactionValidityError::Invalid(InvalidTransaction::Custom(DISPATCH_PAUSED))
+		);
+		assert_ok!(check_role_pre_dispatch(&BOB, &open_call));
+	});
+}
+
+#[test]
+fn pause_role_dispatch_should_lift_automatically() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::pause_role_dispatch(root(), true, Some(1)),
+			RolesError::InvalidPauseEnd
+		);
+		assert_ok!(Roles::pause_role_dispatch(root(), true, Some(5)));
+
+		System::set_block_number(4);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::DispatchPaused
+		);
+
+		System::set_block_number(5);
+		assert_eq!(Roles::active_pause(), None);
+		assert_ok!(check_role_pre_dispatch(&ALICE, &remark_call()));
+		assert_ok!(Roles::dispatch_call_with_role(
+			signed_as(ALICE),
+			remark_call(),
+			remarker_role()
+		));
+		assert!(Roles::pause_state().is_some());
+
+		assert_ok!(Roles::unpause_role_dispatch(root()));
+		System::assert_last_event(RolesEvent::RoleDispatchUnpaused.into());
+		assert_eq!(Roles::pause_state(), None);
+		assert_noop!(Roles::unpause_role_dispatch(root()), RolesError::DispatchNotPaused);
+	});
+}
+
+#[test]
+fn force_remove_role_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), CHARLIE, remarker_role()));
+		assert_ok!(Roles::add_role_denial(root(), remarker_role(), force_set_balance_call()));
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		assert_ok!(Roles::set_role_admin(root(), remarker_role(), Some(balancer_role())));
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+		let group_name = remarker_group();
+		assert_ok!(Roles::invite_to_role(root(), 4, remarker_role()));
+
+		assert_ok!(Roles::force_remove_role(root(), remarker_role()));
+		System::assert_has_event(
+			RolesEvent::RoleRemovalStarted { role_name: remarker_role() }.into(),
+		);
+		assert!(Roles::role_removal(remarker_role()).is_some());
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::RoleBeingRemoved
+		);
+		assert_noop!(Roles::resume_role(root(), remarker_role()), RolesError::RoleBeingRemoved);
+
+		let mut calls = 1;
+		while Roles::roles(remarker_role()).is_some() {
+			assert_ok!(Roles::force_remove_role(root(), remarker_role()));
+			calls += 1;
+		}
+		assert!(calls > 2);
+		System::assert_last_event(RolesEvent::RoleRemoved { role_name: remarker_role() }.into());
+
+		assert_eq!(Roles::role_removal(remarker_role()), None);
+		assert!(!account_set_contains(&ALICE, &remarker_role()));
+		assert!(!account_set_contains(&BOB, &remarker_role()));
+		assert!(!account_set_contains(&CHARLIE, &remarker_role()));
+		assert!(!call_set_contains(&remark_metadata(), &remarker_role()));
+		assert!(!Roles::call_denied_roles(force_set_balance_metadata())
+			.unwrap_or_default()
+			.contains(&remarker_role()));
+		assert_eq!(Roles::role_parents(default_empty_role()), None);
+		assert_eq!(Roles::role_admin(remarker_role()), None);
+		assert_eq!(Roles::exclusive_roles(default_empty_role()), None);
+		assert_eq!(Roles::group_roles(group_name), None);
+		assert_eq!(Roles::invitation(4, remarker_role()), None);
+		assert_consumers_counter_eq(&default_empty_role(), 0);
+		assert_consumers_counter_eq(&balancer_role(), 2);
+	});
+}
+
+#[test]
+fn force_remove_role_should_prevent_new_references() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::assign_role(root(), CHARLIE, remarker_role()));
+		assert_ok!(Roles::force_remove_role(root(), remarker_role()));
+		assert!(Roles::role_removal(remarker_role()).is_some());
+
+		assert_noop!(Roles::assign_role(root(), 4, remarker_role()), RolesError::RoleBeingRemoved);
+		assert_noop!(
+			Roles::add_call(root(), remarker_role(), force_set_balance_call()),
+			RolesError::RoleBeingRemoved
+		);
+		assert_noop!(
+			Roles::invite_to_role(root(), 4, remarker_role()),
+			RolesError::RoleBeingRemoved
+		);
+		assert_noop!(
+			Roles::add_role_denial(root(), remarker_role(), force_set_balance_call()),
+			RolesError::RoleBeingRemoved
+		);
+		assert_noop!(
+			Roles::add_parent_role(root(), default_empty_role(), remarker_role()),
+			RolesError::RoleBeingRemoved
+		);
+		assert_noop!(
+			Roles::set_role_admin(root(), default_empty_role(), Some(remarker_role())),
+			RolesError::RoleBeingRemoved
+		);
+	});
+}
+
+#[test]
+fn force_remove_role_should_clear_account_role_usage() {
+	new_test_ext().execute_with(|| {
+		let scribe = role_name(b"Scribe");
+		let rate_limit = RateLimit { max_calls: 2, period: 10, scope: RateLimitScope::PerAccount };
+		assert_ok!(Roles::create_role(
+			root(),
+			scribe.clone(),
+			false,
+			crate::RoleDispatchOrigin::Regular
+		));
+		assert_ok!(Roles::add_call(root(), scribe.clone(), remark_call()));
+		assert_ok!(Roles::set_role_rate_limit(root(), scribe.clone(), Some(rate_limit)));
+		let group_name = remarker_group();
+		assert_ok!(Roles::assign_role_to_group(root(), group_name.clone(), scribe.clone()));
+		for who in [BOB, CHARLIE] {
+			assert_ok!(Roles::dispatch_call_with_role(
+				signed_as(who),
+				remark_call(),
+				scribe.clone()
+			));
+		}
+		assert_ok!(Roles::unassign_role_from_group(root(), group_name, scribe.clone()));
+		assert_ok!(Roles::remove_call(root(), scribe.clone(), remark_call()));
+		assert_consumers_counter_eq(&scribe, 0);
+		assert_noop!(Roles::remove_role(root(), scribe.clone()), DispatchError::ConsumerRemaining);
+
+		while Roles::roles(scribe.clone()).is_some() {
+			assert_ok!(Roles::force_remove_role(root(), scribe.clone()));
+		}
+
+		assert!(Roles::account_role_usage(&scribe, BOB).is_none());
+		assert!(Roles::account_role_usage(&scribe, CHARLIE).is_none());
+	});
+}
+
+#[test]
+fn force_remove_role_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Roles::remove_role(root(), remarker_role()), DispatchError::ConsumerRemaining);
+		assert_noop!(
+			Roles::force_remove_role(signed_as(ALICE), remarker_role()),
+			RolesError::BadOrigin
+		);
+		assert_noop!(
+			Roles::force_remove_role(root(), role_name(b"NoRole")),
+			RolesError::RoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn rename_role_should_work() {
+	new_test_ext().execute_with(|| {
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::add_parent_role(root(), default_empty_role(), remarker_role()));
+		assert_ok!(Roles::set_role_admin(root(), remarker_role(), Some(balancer_role())));
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+		let group_name = remarker_group();
+		assert_ok!(Roles::invite_to_role(root(), 4, remarker_role()));
+		assert_ok!(Roles::set_role_metadata(root(), remarker_role(), Some(role_metadata(BOB))));
+		assert_consumers_counter_eq(&remarker_role(), 8);
+
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		System::assert_has_event(
+			RolesEvent::RoleRenameStarted {
+				role_name: remarker_role(),
+				new_role_name: scribe.clone(),
+			}
+			.into(),
+		);
+		assert_noop!(
+			Roles::rename_role(root(), remarker_role(), role_name(b"Writer")),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::force_remove_role(root(), remarker_role()),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(Roles::remove_role(root(), scribe.clone()), RolesError::RoleBeingRenamed);
+		assert_noop!(
+			Roles::force_remove_role(root(), scribe.clone()),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::rename_role(root(), scribe.clone(), role_name(b"Writer")),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::clone_role(root(), scribe.clone(), role_name(b"Writer"), false),
+			RolesError::RoleBeingRenamed
+		);
+		assert_ok!(Roles::set_role_metadata(root(), remarker_role(), Some(role_metadata(CHARLIE))));
+
+		let mut calls = 1;
+		while Roles::roles(remarker_role()).is_some() {
+			for who in [ALICE, BOB] {
+				let held_role = if account_set_contains(&who, &scribe) {
+					scribe.clone()
+				} else {
+					remarker_role()
+				};
+				assert_ok!(Roles::dispatch_call_with_role(
+					signed_as(who),
+					remark_call(),
+					held_role
+				));
+			}
+			assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+			calls += 1;
+		}
+		assert!(calls > 2);
+		System::assert_last_event(
+			RolesEvent::RoleRenamed { role_name: remarker_role(), new_role_name: scribe.clone() }
+				.into(),
+		);
+
+		assert_eq!(Roles::role_rename(remarker_role()), None);
+		assert_consumers_counter_eq(&scribe, 8);
+		assert_consumers_counter_eq(&balancer_role(), 3);
+		assert!(account_set_contains(&ALICE, &scribe));
+		assert!(account_set_contains(&BOB, &scribe));
+		assert!(!account_set_contains(&ALICE, &remarker_role()));
+		assert!(call_set_contains(&remark_metadata(), &scribe));
+		assert!(!call_set_contains(&remark_metadata(), &remarker_role()));
+		assert!(Roles::role_parents(default_empty_role()).unwrap().contains(&scribe));
+		assert_eq!(Roles::role_admin(scribe.clone()), Some(balancer_role()));
+		assert_eq!(Roles::role_metadata(scribe.clone()), Some(role_metadata(CHARLIE)));
+		assert_eq!(Roles::role_metadata(remarker_role()), None);
+		assert_eq!(Roles::role_rename_source(scribe.clone()), None);
+		assert!(Roles::exclusive_roles(default_empty_role()).unwrap().contains(&scribe));
+		assert!(Roles::group_roles(group_name).unwrap().contains(&scribe));
+		assert!(Roles::invitation(4, scribe.clone()).is_some());
+		assert_ok!(Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), scribe));
+	});
+}
+
+#[test]
+fn rename_role_should_move_proposals() {
+	new_test_ext().execute_with(|| {
+		let treasury = approval_role_with_holders();
+		let vault = role_name(b"Vault");
+		assert_ok!(Roles::propose_call_with_role(
+			signed_as(BOB),
+			force_set_balance_call(),
+			treasury.clone()
+		));
+
+		while Roles::roles(&treasury).is_some() {
+			assert_ok!(Roles::rename_role(root(), treasury.clone(), vault.clone()));
+		}
+		assert_eq!(Roles::proposal(&treasury, force_set_balance_hash()), None);
+		assert!(Roles::proposal(&vault, force_set_balance_hash()).is_some());
+		assert_eq!(Balances::reserved_balance(BOB), 100);
+
+		while Roles::roles(&vault).is_some() {
+			assert_ok!(Roles::force_remove_role(root(), vault.clone()));
+		}
+		System::assert_has_event(
+			RolesEvent::ProposalDropped {
+				role_name: vault.clone(),
+				call_hash: force_set_balance_hash(),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::proposal(&vault, force_set_balance_hash()), None);
+		assert_eq!(Balances::reserved_balance(BOB), 0);
+	});
+}
+
+#[test]
+fn cancel_role_rename_should_work() {
+	new_test_ext().execute_with(|| {
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+
+		assert_ok!(Roles::cancel_role_rename(root(), remarker_role()));
+		System::assert_has_event(
+			RolesEvent::RoleRenameCancelled {
+				role_name: remarker_role(),
+				new_role_name: scribe.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::role_rename(remarker_role()), None);
+		assert_noop!(
+			Roles::rename_role(root(), remarker_role(), role_name(b"Writer")),
+			RolesError::RoleBeingRenamed
+		);
+
+		while Roles::roles(&scribe).is_some() {
+			for who in [ALICE, BOB] {
+				let held_role = if account_set_contains(&who, &scribe) {
+					scribe.clone()
+				} else {
+					remarker_role()
+				};
+				assert_ok!(Roles::dispatch_call_with_role(
+					signed_as(who),
+					remark_call(),
+					held_role
+				));
+			}
+			assert_ok!(Roles::cancel_role_rename(root(), remarker_role()));
+		}
+		System::assert_last_event(
+			RolesEvent::RoleRenamed { role_name: scribe.clone(), new_role_name: remarker_role() }
+				.into(),
+		);
+
+		assert_eq!(Roles::role_rename(scribe.clone()), None);
+		assert_eq!(Roles::role_rename_source(remarker_role()), None);
+		assert_consumers_counter_eq(&remarker_role(), 3);
+		assert!(account_set_contains(&ALICE, &remarker_role()));
+		assert!(account_set_contains(&BOB, &remarker_role()));
+		assert!(call_set_contains(&remark_metadata(), &remarker_role()));
+		assert!(!call_set_contains(&remark_metadata(), &scribe));
+		assert_noop!(
+			Roles::cancel_role_rename(root(), remarker_role()),
+			RolesError::RoleNotBeingRenamed
+		);
+	});
+}
+
+#[test]
+fn rename_role_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::rename_role(root(), remarker_role(), balancer_role()),
+			RolesError::RoleExists
+		);
+		assert_noop!(
+			Roles::rename_role(root(), role_name(b"NoRole"), role_name(b"Scribe")),
+			RolesError::RoleDoesNotExist
+		);
+		assert_noop!(
+			Roles::rename_role(signed_as(ALICE), remarker_role(), role_name(b"Scribe")),
+			RolesError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn update_role_should_work() {
+	new_test_ext().execute_with(|| {
+		let new_settings = RoleSettings {
+			allow_filter_bypassing: true,
+			dispatch_origin: crate::RoleDispatchOrigin::Root,
+		};
+		assert_ok!(Roles::update_role(root(), remarker_role(), new_settings.clone()));
+		System::assert_last_event(
+			RolesEvent::RoleUpdated {
+				role_name: remarker_role(),
+				old_settings: RoleSettings {
+					allow_filter_bypassing: false,
+					dispatch_origin: crate::RoleDispatchOrigin::Regular,
+				},
+				new_settings: new_settings.clone(),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::roles(remarker_role()).unwrap().settings(), new_settings);
+		assert_consumers_counter_eq(&remarker_role(), 2);
+		assert!(account_set_contains(&ALICE, &remarker_role()));
+		assert!(call_set_contains(&remark_metadata(), &remarker_role()));
+
+		assert_noop!(
+			Roles::update_role(signed_as(ALICE), remarker_role(), new_settings.clone()),
+			RolesError::BadOrigin
+		);
+		assert_noop!(
+			Roles::update_role(root(), role_name(b"NoRole"), new_settings.clone()),
+			RolesError::RoleDoesNotExist
+		);
+
+		System::reset_events();
+		assert_ok!(Roles::update_role(root(), remarker_role(), new_settings.clone()));
+		assert!(System::events().is_empty());
+
+		let scribe = role_name(b"Scribe");
+		assert_ok!(Roles::rename_role(root(), remarker_role(), scribe.clone()));
+		assert_noop!(
+			Roles::update_role(root(), remarker_role(), new_settings.clone()),
+			RolesError::RoleBeingRenamed
+		);
+		assert_noop!(
+			Roles::update_role(root(), scribe, new_settings),
+			RolesError::RoleBeingRenamed
+		);
+	});
+}
+
+#[test]
+fn update_role_should_require_new_impersonation_consent() {
+	new_test_ext().execute_with(|| {
+		let signed_as_bob = RoleSettings {
+			allow_filter_bypassing: false,
+			dispatch_origin: crate::RoleDispatchOrigin::SignedAs { who: BOB },
+		};
+		assert_ok!(Roles::update_role(root(), remarker_role(), signed_as_bob.clone()));
+		assert_eq!(Roles::roles(remarker_role()).unwrap().consent(), ImpersonationConsent::Pending);
+		assert_noop!(
+			Roles::dispatch_call_with_role(signed_as(ALICE), remark_call(), remarker_role()),
+			RolesError::ImpersonationNotConsented
+		);
+
+		assert_ok!(Roles::accept_impersonation(signed_as(BOB), remarker_role()));
+		assert_ok!(Roles::update_role(root(), remarker_role(), signed_as_bob));
+		assert_eq!(Roles::roles(remarker_role()).unwrap().consent(), ImpersonationConsent::Given);
+	});
+}
+
+#[test]
+fn set_role_metadata_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Roles::set_role_metadata(root(), remarker_role(), Some(role_metadata(BOB))));
+		System::assert_last_event(
+			RolesEvent::RoleMetadataSet {
+				role_name: remarker_role(),
+				metadata: Some(role_metadata(BOB)),
+			}
+			.into(),
+		);
+		assert_eq!(Roles::role_metadata(remarker_role()), Some(role_metadata(BOB)));
+
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), Some(remarker_role())));
+		assert_ok!(Roles::set_role_metadata(
+			signed_as(ALICE),
+			default_empty_role(),
+			Some(role_metadata(ALICE))
+		));
+		assert_eq!(Roles::role_metadata(default_empty_role()), Some(role_metadata(ALICE)));
+
+		assert_ok!(Roles::set_role_metadata(root(), remarker_role(), None));
+		System::assert_last_event(
+			RolesEvent::RoleMetadataSet { role_name: remarker_role(), metadata: None }.into(),
+		);
+		assert_eq!(Roles::role_metadata(remarker_role()), None);
+
+		assert_ok!(Roles::set_role_admin(root(), default_empty_role(), None));
+		assert_ok!(Roles::remove_role(root(), default_empty_role()));
+		assert_eq!(Roles::role_metadata(default_empty_role()), None);
+	});
+}
+
+#[test]
+fn set_role_metadata_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Roles::set_role_metadata(signed_as(ALICE), remarker_role(), Some(role_metadata(BOB))),
+			RolesError::BadOrigin
+		);
+		assert_noop!(
+			Roles::set_role_metadata(root(), role_name(b"NoRole"), Some(role_metadata(BOB))),
+			RolesError::RoleDoesNotExist
+		);
+	});
+}
+
+#[test]
+fn apply_policy_changes_should_work() {
+	new_test_ext().execute_with(|| {
+		let auditor = role_name(b"Auditor");
+		assert_ok!(Roles::apply_policy_changes(
+			root(),
+			policy_changes(vec![
+				PolicyChange::CreateRole {
+					role_name: auditor.clone(),
+					allow_filter_bypassing: false,
+					allow_dispatch_as: crate::RoleDispatchOrigin::Regular,
+				},
+				PolicyChange::AddCall { role_name: auditor.clone(), call: remark_call() },
+				PolicyChange::AssignRole { who: BOB, role_name: auditor.clone() },
+			])
+		));
+		System::assert_last_event(RolesEvent::PolicyChangesApplied { count: 3 }.into());
+		assert!(call_set_contains(&remark_metadata(), &auditor));
+		assert!(account_set_contains(&BOB, &auditor));
+		assert_consumers_counter_eq(&auditor, 2);
+
+		assert_ok!(Roles::apply_policy_changes(
+			root(),
+			policy_changes(vec![
+				PolicyChange::UnassignRole { who: BOB, role_name: auditor.clone() },
+				PolicyChange::RemoveCall { role_name: auditor.clone(), call: remark_call() },
+			])
+		));
+		System::assert_last_event(RolesEvent::PolicyChangesApplied { count: 2 }.into());
+		assert!(!call_set_contains(&remark_metadata(), &auditor));
+		assert!(!account_set_contains(&BOB, &auditor));
+		assert_consumers_counter_eq(&auditor, 0);
+	});
+}
+
+#[test]
+fn apply_policy_changes_should_fail() {
+	new_test_ext().execute_with(|| {
+		let auditor = role_name(b"Auditor");
+		let changes = policy_changes(vec![
+			PolicyChange::CreateRole {
+				role_name: auditor.clone(),
+				allow_filter_bypassing: false,
+				allow_dispatch_as: crate::RoleDispatchOrigin::Regular,
+			},
+			PolicyChange::AddCall { role_name: auditor.clone(), call: remark_call() },
+			PolicyChange::AssignRole { who: BOB, role_name: role_name(b"NoRole") },
+		]);
+		assert_noop!(
+			Roles::apply_policy_changes(signed_as(ALICE), changes.clone()),
+			RolesError::BadOrigin
+		);
+		assert_noop!(Roles::apply_policy_changes(root(), changes), RolesError::RoleDoesNotExist);
+		assert!(Roles::roles(&auditor).is_none());
+		assert!(!call_set_contains(&remark_metadata(), &auditor));
+	});
+}
+
+#[test]
+fn clone_role_should_work() {
+	new_test_ext().execute_with(|| {
+		let team = role_name(b"Team");
+		let validity = AssignmentValidity { not_before: None, expires_at: Some(100) };
+		assert_ok!(Roles::assign_role_with_validity(root(), BOB, remarker_role(), None, Some(100)));
+		assert_consumers_counter_eq(&remarker_role(), 3);
+
+		assert_ok!(Roles::clone_role(root(), remarker_role(), team.clone(), true));
+		System::assert_has_event(
+			RolesEvent::RoleCloneStarted {
+				role_name: remarker_role(),
+				new_role_name: team.clone(),
+			}
+			.into(),
+		);
+		while Roles::role_clone(&team).is_some() {
+			assert_ok!(Roles::clone_role(root(), remarker_role(), team.clone(), true));
+		}
+		System::assert_last_event(
+			RolesEvent::RoleCloned { role_name: remarker_role(), new_role_name: team.clone() }
+				.into(),
+		);
+		assert!(call_set_contains(&remark_metadata(), &team));
+		assert!(account_set_contains(&ALICE, &team));
+		assert!(account_set_contains(&BOB, &team));
+		assert_eq!(Roles::assignment_validity(BOB, team.clone()), Some(validity));
+		assert_consumers_counter_eq(&team, 3);
+		assert_consumers_counter_eq(&remarker_role(), 3);
+		assert!(call_set_contains(&remark_metadata(), &remarker_role()));
+		assert!(account_set_contains(&BOB, &remarker_role()));
+
+		let treasurer = role_name(b"Treasurer");
+		while Roles::roles(&treasurer).is_none() || Roles::role_clone(&treasurer).is_some() {
+			assert_ok!(Roles::clone_role(root(), balancer_role(), treasurer.clone(), false));
+		}
+		assert_eq!(
+			Roles::roles(&treasurer).unwrap().settings(),
+			Roles::roles(balancer_role()).unwrap().settings()
+		);
+		assert!(call_set_contains(&force_set_balance_metadata(), &treasurer));
+		assert!(!account_set_contains(&ALICE, &treasurer));
+		assert_consumers_counter_eq(&treasurer, 1);
+	});
+}
+
+#[test]
+fn clone_role_should_fail() {
+	new_test_ext().execute_with(|| {
+		let team = role_name(b"Team");
+		assert_noop!(
+			Roles::clone_role(signed_as(ALICE), remarker_role(), team.clone(), true),
+			RolesError::BadOrigin
+		);
+		assert_noop!(
+			Roles::clone_role(root(), role_name(b"NoRole"), team.clone(), true),
+			RolesError::RoleDoesNotExist
+		);
+		assert_noop!(
+			Roles::clone_role(root(), remarker_role(), balancer_role(), true),
+			RolesError::RoleExists
+		);
+
+		assert_ok!(Roles::assign_role(root(), BOB, remarker_role()));
+		assert_ok!(Roles::clone_role(root(), remarker_role(), team.clone(), true));
+		assert!(Roles::role_clone(&team).is_some());
+		assert_noop!(
+			Roles::clone_role(root(), remarker_role(), team.clone(), false),
+			RolesError::RoleBeingCloned
+		);
+		assert_noop!(
+			Roles::clone_role(root(), balancer_role(), team.clone(), true),
+			RolesError::RoleBeingCloned
+		);
+		assert_noop!(
+			Roles::rename_role(root(), team.clone(), role_name(b"Crew")),
+			RolesError::RoleBeingCloned
+		);
+		assert_noop!(Roles::remove_role(root(), remarker_role()), RolesError::RoleBeingCloned);
+		assert_noop!(
+			Roles::force_remove_role(root(), remarker_role()),
+			RolesError::RoleBeingCloned
+		);
+		assert_noop!(
+			Roles::rename_role(root(), remarker_role(), role_name(b"Crew")),
+			RolesError::RoleBeingCloned
+		);
+		assert_noop!(
+			Roles::clone_role(root(), remarker_role(), role_name(b"Crew"), true),
+			RolesError::RoleBeingCloned
+		);
+		assert_noop!(
+			Roles::clone_role(root(), team.clone(), role_name(b"Crew"), true),
+			RolesError::RoleBeingCloned
+		);
+
+		while Roles::roles(&team).is_some() {
+			assert_ok!(Roles::force_remove_role(root(), team.clone()));
+		}
+		assert_eq!(Roles::role_clone(&team), None);
+		assert_eq!(Roles::role_clone_target(remarker_role()), None);
+		assert_ok!(Roles::clone_role(root(), remarker_role(), role_name(b"Crew"), true));
+
+		assert_ok!(Roles::suspend_role(root(), balancer_role()));
+		assert_noop!(
+			Roles::clone_role(root(), balancer_role(), role_name(b"Treasurer"), false),
+			RolesError::RoleSuspended
+		);
+	});
+}
+
+#[test]
+fn clone_role_should_copy_links_and_budget() {
+	new_test_ext().execute_with(|| {
+		let team = role_name(b"Team");
+		let budget = SpendingBudget { limit: 100, period: 10 };
+		assert_ok!(Roles::add_parent_role(root(), remarker_role(), default_empty_role()));
+		assert_ok!(Roles::add_pallet_wildcard(root(), remarker_role(), balances_pallet_index()));
+		assert_ok!(Roles::add_role_denial(root(), remarker_role(), force_set_balance_call()));
+		assert_ok!(Roles::set_role_budget(root(), remarker_role(), Some(budget.clone())));
+		let group_name = remarker_group();
+
+		while Roles::roles(&team).is_none() || Roles::role_clone(&team).is_some() {
+			assert_ok!(Roles::clone_role(root(), remarker_role(), team.clone(), true));
+		}
+		assert!(Roles::role_parents(&team).unwrap().contains(&default_empty_role()));
+		assert!(Roles::pallet_roles(balances_pallet_index()).unwrap().contains(&team));
+		assert!(Roles::call_denied_roles(force_set_balance_metadata()).unwrap().contains(&team));
+		assert!(Roles::group_roles(&group_name).unwrap().contains(&team));
+		assert_eq!(Roles::role_budget(&team), Some(budget));
+		assert_eq!(Roles::role_clone_target(remarker_role()), None);
+		assert_consumers_counter_eq(&team, 6);
+		assert_consumers_counter_eq(&default_empty_role(), 2);
+	});
+}
+
+#[test]
+fn clone_role_should_copy_exclusions_admin_and_membership_rule() {
+	new_test_ext().execute_with(|| {
+		let team = role_name(b"Team");
+		assert_ok!(Roles::add_exclusive_roles(root(), remarker_role(), default_empty_role()));
+		assert_ok!(Roles::set_role_admin(root(), remarker_role(), Some(balancer_role())));
+
+		while Roles::roles(&team).is_none() || Roles::role_clone(&team).is_some() {
+			assert_ok!(Roles::clone_role(root(), remarker_role(), team.clone(), true));
+		}
+		assert!(Roles::exclusive_roles(&team).unwrap().contains(&default_empty_role()));
+		assert!(Roles::exclusive_roles(default_empty_role()).unwrap().contains(&team));
+		assert_eq!(Roles::role_admin(&team), Some(balancer_role()));
+		assert_consumers_counter_eq(&team, 4);
+		assert_consumers_counter_eq(&default_empty_role(), 2);
+		assert_consumers_counter_eq(&balancer_role(), 4);
+		assert_ok!(Roles::assign_role(root(), BOB, team.clone()));
+		assert_noop!(
+			Roles::assign_role(root(), BOB, default_empty_role()),
+			RolesError::ExclusiveRoleHeld
+		);
+
+		let whale = whale_role(1_000);
+		let shark = role_name(b"Shark");
+		while Roles::roles(&shark).is_none() || Roles::role_clone(&shark).is_some() {
+			assert_ok!(Roles::clone_role(root(), whale.clone(), shark.clone(), false));
+		}
+		assert_eq!(
+			Roles::role_membership_rules().get(&shark),
+			Roles::role_membership_rules().get(&whale)
+		);
+	});
+}
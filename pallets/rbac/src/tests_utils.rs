@@ -1,17 +1,30 @@
 use crate::{
-	mock::{AccountId, BalancesCall, RawOrigin, Roles, RuntimeCall, SystemCall, Test, ALICE},
+	mock::{
+		AccountId, Balances, BalancesCall, MockCallConstraint, MockMembershipRule, RawOrigin,
+		Roles, RuntimeCall, SystemCall, Test, ALICE, BOB, CHARLIE,
+	},
 	primitives::CallMetadata,
 	traits::GetCallMetadataIndecies,
-	Config, Error, Event, RoleNameOf,
+	CallConstraintsListOf, CheckRole, Config, Error, Event, GroupNameOf, PolicyChangeOf,
+	PolicyChangesOf, RoleDispatchOrigin, RoleMetadata, RoleMetadataOf, RoleNameOf, SpendingBudget,
 };
+use codec::Encode;
+use frame_support::{assert_ok, dispatch::DispatchInfo, traits::Currency};
 use frame_system::pallet_prelude::OriginFor;
-use sp_runtime::BoundedBTreeSet;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, SignedExtension},
+	transaction_validity::{TransactionValidity, TransactionValidityError},
+	BoundedBTreeSet,
+};
 
 pub(crate) type RolesEvent = Event<Test>;
 pub(crate) type RolesError = Error<Test>;
 pub(crate) type SystemEvent = frame_system::Event<Test>;
 pub(crate) type CallRolesSet =
 	BoundedBTreeSet<RoleNameOf<Test>, <Test as Config>::RolesPerCallLimit>;
+pub(crate) type AssignmentScopesSet =
+	BoundedBTreeSet<AccountId, <Test as Config>::ScopesPerAssignmentLimit>;
 
 pub(crate) fn role_name(name: &[u8]) -> RoleNameOf<Test> {
 	name.to_vec().try_into().expect("Expected to generate a role name")
@@ -44,6 +57,10 @@ pub(crate) fn force_set_balance_call() -> Box<RuntimeCall> {
 	RuntimeCall::Balances(BalancesCall::force_set_balance { who: ALICE, new_free: 0 }).into()
 }
 
+pub(crate) fn force_set_balance_call_with(new_free: u128) -> Box<RuntimeCall> {
+	RuntimeCall::Balances(BalancesCall::force_set_balance { who: ALICE, new_free }).into()
+}
+
 pub(crate) fn force_set_balance_metadata() -> CallMetadata {
 	force_set_balance_call().get_call_metadata_indicies().into()
 }
@@ -61,6 +78,107 @@ pub(crate) fn deprecated_metadata() -> CallMetadata {
 	deprecated_call().get_call_metadata_indicies().into()
 }
 
+pub(crate) fn balances_pallet_index() -> u64 {
+	force_set_balance_metadata().pallet_index
+}
+
+pub(crate) fn treasury_role_with_budget(budget: SpendingBudget<u128>) -> RoleNameOf<Test> {
+	let role_name = role_name(b"Treasury");
+	assert_ok!(Roles::create_role(root(), role_name.clone(), false, RoleDispatchOrigin::Root));
+	assert_ok!(Roles::assign_role(root(), BOB, role_name.clone()));
+	assert_ok!(Roles::add_pallet_wildcard(root(), role_name.clone(), balances_pallet_index()));
+	assert_ok!(Roles::set_role_budget(root(), role_name.clone(), Some(budget)));
+	role_name
+}
+
+pub(crate) fn force_set_balance_hash() -> H256 {
+	BlakeTwo256::hash(&force_set_balance_call().encode())
+}
+
+pub(crate) fn approval_role_with_holders() -> RoleNameOf<Test> {
+	let role_name = role_name(b"Treasury");
+	assert_ok!(Roles::create_role(root(), role_name.clone(), false, RoleDispatchOrigin::Root));
+	assert_ok!(Roles::add_call(root(), role_name.clone(), force_set_balance_call()));
+	assert_ok!(Roles::set_approval_threshold(
+		root(),
+		role_name.clone(),
+		force_set_balance_call(),
+		Some(2)
+	));
+	assert_ok!(Roles::assign_role(root(), BOB, role_name.clone()));
+	assert_ok!(Roles::assign_role(root(), CHARLIE, role_name.clone()));
+	Balances::make_free_balance_be(&BOB, 1_000);
+	role_name
+}
+
+pub(crate) fn impersonating_role(who: AccountId) -> RoleNameOf<Test> {
+	let role_name = role_name(b"Impersonator");
+	assert_ok!(Roles::create_role(
+		root(),
+		role_name.clone(),
+		false,
+		RoleDispatchOrigin::SignedAs { who }
+	));
+	assert_ok!(Roles::add_call(root(), role_name.clone(), remark_call()));
+	assert_ok!(Roles::assign_role(root(), BOB, role_name.clone()));
+	role_name
+}
+
+pub(crate) fn remarker_group() -> GroupNameOf<Test> {
+	let group_name = role_name(b"Remarkers");
+	assert_ok!(Roles::create_group(root(), group_name.clone()));
+	assert_ok!(Roles::add_group_member(root(), group_name.clone(), BOB));
+	assert_ok!(Roles::add_group_member(root(), group_name.clone(), CHARLIE));
+	assert_ok!(Roles::assign_role_to_group(root(), group_name.clone(), remarker_role()));
+	group_name
+}
+
+pub(crate) fn role_metadata(owner: AccountId) -> RoleMetadataOf<Test> {
+	let mut tags = BoundedBTreeSet::new();
+	tags.try_insert(b"governance".to_vec().try_into().expect("Expected to generate a role tag"))
+		.expect("Expected to add a role tag");
+	RoleMetadata {
+		description: b"Posts remarks".to_vec().try_into().expect("Expected a short description"),
+		owner: Some(owner),
+		uri: b"ipfs://policy".to_vec().try_into().expect("Expected a short URI"),
+		tags,
+	}
+}
+
+pub(crate) fn policy_changes(changes: Vec<PolicyChangeOf<Test>>) -> PolicyChangesOf<Test> {
+	changes.try_into().expect("Expected to generate a batch of policy changes")
+}
+
+pub(crate) fn call_constraints(
+	constraints: Vec<MockCallConstraint>,
+) -> CallConstraintsListOf<Test> {
+	constraints.try_into().expect("Expected to generate a list of call constraints")
+}
+
+pub(crate) fn force_set_balance_call_for(who: AccountId) -> Box<RuntimeCall> {
+	RuntimeCall::Balances(BalancesCall::force_set_balance { who, new_free: 0 }).into()
+}
+
+pub(crate) fn scoped_balancer_role(scope: AccountId) -> RoleNameOf<Test> {
+	let role_name = role_name(b"ScopedBalancer");
+	assert_ok!(Roles::create_role(root(), role_name.clone(), false, RoleDispatchOrigin::Root));
+	assert_ok!(Roles::add_call(root(), role_name.clone(), force_set_balance_call()));
+	assert_ok!(Roles::assign_role_with_scope(root(), BOB, role_name.clone(), scope));
+	role_name
+}
+
+pub(crate) fn whale_role(min_free: u128) -> RoleNameOf<Test> {
+	let role_name = role_name(b"Whale");
+	assert_ok!(Roles::create_role(root(), role_name.clone(), false, RoleDispatchOrigin::Regular));
+	assert_ok!(Roles::add_call(root(), role_name.clone(), remark_call()));
+	assert_ok!(Roles::set_role_membership_rule(
+		root(),
+		role_name.clone(),
+		Some(MockMembershipRule::MinFreeBalance(min_free))
+	));
+	role_name
+}
+
 pub(crate) fn call_set_with(names: Vec<RoleNameOf<Test>>) -> Option<CallRolesSet> {
 	let mut new_set = CallRolesSet::new();
 	names.into_iter().for_each(|name| {
@@ -69,6 +187,14 @@ pub(crate) fn call_set_with(names: Vec<RoleNameOf<Test>>) -> Option<CallRolesSet
 	Some(new_set)
 }
 
+pub(crate) fn scope_set_with(scopes: Vec<AccountId>) -> Option<AssignmentScopesSet> {
+	let mut new_set = AssignmentScopesSet::new();
+	scopes.into_iter().for_each(|scope| {
+		new_set.try_insert(scope).unwrap();
+	});
+	Some(new_set)
+}
+
 pub(crate) fn account_set_contains(acc: &AccountId, name: &RoleNameOf<Test>) -> bool {
 	Roles::account_roles(acc).unwrap_or_default().contains(name)
 }
@@ -81,6 +207,17 @@ pub(crate) fn assert_consumers_counter_eq(name: &RoleNameOf<Test>, counter: u128
 	assert_eq!(Roles::roles(name).unwrap().get_consumers_counter(), counter);
 }
 
+pub(crate) fn check_role_pre_dispatch(
+	who: &AccountId,
+	call: &RuntimeCall,
+) -> Result<(), TransactionValidityError> {
+	CheckRole::<Test>::new().pre_dispatch(who, call, &DispatchInfo::default(), 1)
+}
+
+pub(crate) fn check_role_validate(who: &AccountId, call: &RuntimeCall) -> TransactionValidity {
+	CheckRole::<Test>::new().validate(who, call, &DispatchInfo::default(), 1)
+}
+
 pub(crate) fn root() -> OriginFor<Test> {
 	RawOrigin::Root.into()
 }
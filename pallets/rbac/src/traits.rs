@@ -1,3 +1,5 @@
+use codec::MaxEncodedLen;
+use frame_support::Parameter;
 use sp_runtime::transaction_validity::TransactionValidityError;
 
 pub trait CallValidator<CallMetadata, AccountId> {
@@ -19,3 +21,47 @@ pub trait CallValidator<CallMetadata, AccountId> {
 pub trait GetCallMetadataIndecies {
 	fn get_call_metadata_indicies(&self) -> (u64, u8);
 }
+
+pub trait CallSpending<Balance> {
+	/// Returns the amount of funds moved by the call, which is counted against the spending budget
+	/// of the role used to dispatch it. Calls that do not move funds should return zero.
+	fn spent_amount(&self) -> Balance;
+}
+
+pub trait ResourceExtractor {
+	/// Identifies a resource a call operates on, e.g. an asset, collection or pool id, to which
+	/// role assignments can be scoped.
+	type Resource: Parameter + MaxEncodedLen + Ord;
+
+	/// Returns the resource the call operates on, if any. Calls without a resource can only be
+	/// dispatched with roles whose assignment is not scoped.
+	fn resource(&self) -> Option<Self::Resource>;
+}
+
+pub trait MembershipRule<AccountId> {
+	/// Describes a condition on account's on-chain attributes, e.g. a minimum free balance or an
+	/// identity judgement, under which the account holds a role without being assigned to it.
+	type Rule: Parameter + MaxEncodedLen;
+
+	/// Checks if the account satisfies the rule.
+	///
+	/// # Parameters
+	///
+	/// - `rule`: The membership rule attached to the role.
+	/// - `who`: The account to check.
+	fn is_satisfied(rule: &Self::Rule, who: &AccountId) -> bool;
+}
+
+pub trait CallConstraint {
+	/// Describes a restriction on call's arguments which can be attached to a role's permission
+	/// to execute the call, e.g. a maximum amount or a fixed destination account.
+	type Constraint: Parameter + MaxEncodedLen;
+
+	/// Checks if the call's arguments satisfy the constraint.
+	///
+	/// # Parameters
+	///
+	/// - `constraint`: The constraint attached to the role's permission to execute the call.
+	///   Implementations should return `false` for constraints that do not apply to the call.
+	fn satisfies(&self, constraint: &Self::Constraint) -> bool;
+}
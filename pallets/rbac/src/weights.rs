@@ -1,13 +1,10 @@
-
-//! Autogenerated weights for `pallet_rbac`
+//! Placeholder weights for `pallet_rbac`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
-//! DATE: 2023-09-11, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `MacBook-Pro-qwerty.local`, CPU: `<UNKNOWN>`
-//! EXECUTION: ``, WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//! The values below are hand-written estimates laid out like the benchmark CLI output; they have
+//! not been measured on reference hardware. Regenerate this file before relying on it in a
+//! production runtime.
 
-// Executed Command:
+// Regenerate with:
 // ./target/release/node-template
 // benchmark
 // pallet
@@ -36,6 +33,62 @@ pub trait WeightInfo {
 	fn assign_role() -> Weight;
 	fn unassign_role() -> Weight;
 	fn dispatch_call_with_role() -> Weight;
+	fn add_parent_role(n: u32, ) -> Weight;
+	fn remove_parent_role() -> Weight;
+	fn assign_role_with_validity() -> Weight;
+	fn expire_assignment() -> Weight;
+	fn set_role_admin() -> Weight;
+	fn add_pallet_wildcard() -> Weight;
+	fn remove_pallet_wildcard() -> Weight;
+	fn set_call_constraints() -> Weight;
+	fn set_role_rate_limit() -> Weight;
+	fn set_role_budget() -> Weight;
+	fn add_role_denial() -> Weight;
+	fn remove_role_denial() -> Weight;
+	fn add_account_denial() -> Weight;
+	fn remove_account_denial() -> Weight;
+	fn set_enforcement_mode() -> Weight;
+	fn add_public_call() -> Weight;
+	fn remove_public_call() -> Weight;
+	fn add_exclusive_roles() -> Weight;
+	fn remove_exclusive_roles() -> Weight;
+	fn set_approval_threshold() -> Weight;
+	fn propose_call_with_role() -> Weight;
+	fn approve_call() -> Weight;
+	fn cancel_proposal() -> Weight;
+	fn remove_expired_proposal() -> Weight;
+	fn accept_impersonation() -> Weight;
+	fn revoke_impersonation() -> Weight;
+	fn create_group() -> Weight;
+	fn remove_group() -> Weight;
+	fn add_group_member() -> Weight;
+	fn remove_group_member() -> Weight;
+	fn assign_role_to_group() -> Weight;
+	fn unassign_role_from_group() -> Weight;
+	fn assign_role_with_scope() -> Weight;
+	fn set_role_membership_rule() -> Weight;
+	fn invite_to_role() -> Weight;
+	fn accept_role_invitation() -> Weight;
+	fn decline_role_invitation() -> Weight;
+	fn cancel_role_invitation() -> Weight;
+	fn remove_expired_invitation() -> Weight;
+	fn renounce_role() -> Weight;
+	fn renounce_all_roles() -> Weight;
+	fn suspend_role() -> Weight;
+	fn resume_role() -> Weight;
+	fn pause_role_dispatch() -> Weight;
+	fn unpause_role_dispatch() -> Weight;
+	fn force_remove_role(n: u32, ) -> Weight;
+	fn rename_role(n: u32, ) -> Weight;
+	fn cancel_role_rename(n: u32, ) -> Weight;
+	fn update_role() -> Weight;
+	fn set_role_metadata() -> Weight;
+	fn apply_create_role_change() -> Weight;
+	fn apply_add_call_change() -> Weight;
+	fn apply_remove_call_change() -> Weight;
+	fn apply_assign_role_change() -> Weight;
+	fn apply_unassign_role_change() -> Weight;
+	fn clone_role(n: u32, ) -> Weight;
 }
 
 /// Weight functions for `pallet_rbac`.
@@ -55,6 +108,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	}
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:0 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:0 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:0 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:0 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
 	fn remove_role() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `88`
@@ -62,8 +125,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		// Minimum execution time: 13_000_000 picoseconds.
 		Weight::from_parts(14_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 3598))
-			.saturating_add(T::DbWeight::get().reads(1))
-			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
@@ -137,104 +200,2236 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
-}
-
-// For backwards compatibility and tests
-impl WeightInfo for () {
-	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Storage: `Roles::Roles` (r:2 w:2)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	fn create_role() -> Weight {
+	/// Storage: `Roles::RoleParents` (r:4 w:1)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:0)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleHolders` (r:101 w:0)
+	/// Proof: `Roles::RoleHolders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleGroups` (r:1 w:0)
+	/// Proof: `Roles::RoleGroups` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:100 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:100 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:51 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn add_parent_role(n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `4`
-		//  Estimated: `3598`
-		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3598))
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `312 + n * (121 ±0)`
+		//  Estimated: `168584 + n * (8821 ±0)`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 168584))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(61))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 8821).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:1)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn remove_parent_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `6206`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6206))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	fn remove_role() -> Weight {
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExpiringAssignments` (r:1 w:1)
+	/// Proof: `Roles::ExpiringAssignments` (`max_values`: None, `max_size`: Some(1534), added: 4009, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:0 w:1)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	fn assign_role_with_validity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `88`
-		//  Estimated: `3598`
-		// Minimum execution time: 13_000_000 picoseconds.
-		Weight::from_parts(14_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 3598))
-			.saturating_add(RocksDbWeight::get().reads(1))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Estimated: `4999`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4999))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	/// Storage: `Roles::AssignmentValidities` (r:1 w:1)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `Roles::CallRoles` (r:1 w:1)
-	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
-	fn add_call() -> Weight {
+	fn expire_assignment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `4534`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::Roles` (r:3 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:1)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn set_role_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `8814`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8814))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:1)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn add_pallet_wildcard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `88`
 		//  Estimated: `4511`
 		// Minimum execution time: 19_000_000 picoseconds.
-		Weight::from_parts(19_000_000, 0)
+		Weight::from_parts(20_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4511))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `Roles::CallRoles` (r:1 w:1)
-	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
-	fn remove_call() -> Weight {
+	/// Storage: `Roles::PalletRoles` (r:1 w:1)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn remove_pallet_wildcard() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `142`
 		//  Estimated: `4511`
 		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:0 w:1)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	fn set_call_constraints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	fn set_role_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:0 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:0 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	fn set_role_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `3598`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:1)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn add_role_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4511`
+		// Minimum execution time: 19_000_000 picoseconds.
 		Weight::from_parts(20_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4511))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:1)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn remove_role_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `4511`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::AccountDenials` (r:1 w:1)
+	/// Proof: `Roles::AccountDenials` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn add_account_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3538`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3538))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::AccountDenials` (r:1 w:1)
+	/// Proof: `Roles::AccountDenials` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn remove_account_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80`
+		//  Estimated: `3538`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3538))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::ActiveEnforcementMode` (r:0 w:1)
+	/// Proof: `Roles::ActiveEnforcementMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_enforcement_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::PublicCalls` (r:1 w:1)
+	/// Proof: `Roles::PublicCalls` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn add_public_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3490`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3490))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::PublicCalls` (r:1 w:1)
+	/// Proof: `Roles::PublicCalls` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn remove_public_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3490`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3490))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:2 w:2)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:0)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:51 w:0)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn add_exclusive_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `163776`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 163776))
+			.saturating_add(T::DbWeight::get().reads(56))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:2 w:2)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn remove_exclusive_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `7206`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7206))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:0 w:1)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_approval_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `4511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
 	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
-	fn assign_role() -> Weight {
+	/// Storage: `Roles::AssignmentValidities` (r:1 w:0)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountDenials` (r:1 w:0)
+	/// Proof: `Roles::AccountDenials` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1045), added: 3520, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(308), added: 2783, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:1 w:0)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2138), added: 4613, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:1 w:0)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn propose_call_with_role() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `88`
+		//  Measured:  `402`
 		//  Estimated: `4534`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:1 w:0)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn approve_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1513`
+		//  Estimated: `4795`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1446`
+		//  Estimated: `4795`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_expired_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1446`
+		//  Estimated: `4795`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn accept_impersonation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn revoke_impersonation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	fn create_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `5120`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	fn remove_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `5120`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:1)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	fn add_group_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `5120`
 		// Minimum execution time: 18_000_000 picoseconds.
 		Weight::from_parts(19_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 4534))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:1)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	fn remove_group_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `244`
+		//  Estimated: `5120`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupMembers` (r:1 w:0)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:1)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:5 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:5 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn assign_role_to_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1432`
+		//  Estimated: `27715`
+		// Minimum execution time: 96_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 27715))
+			.saturating_add(T::DbWeight::get().reads(14))
+			.saturating_add(T::DbWeight::get().writes(2))
 	}
 	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:1)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	fn unassign_role_from_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3780`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3780))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
 	/// Storage: `Roles::AccountRoles` (r:1 w:1)
 	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
-	fn unassign_role() -> Weight {
+	/// Storage: `Roles::AssignmentScopes` (r:1 w:1)
+	/// Proof: `Roles::AssignmentScopes` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn assign_role_with_scope() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `166`
+		//  Measured:  `173`
 		//  Estimated: `4534`
-		// Minimum execution time: 20_000_000 picoseconds.
-		Weight::from_parts(21_000_000, 0)
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 0)
 			.saturating_add(Weight::from_parts(0, 4534))
-			.saturating_add(RocksDbWeight::get().reads(2))
-			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
-	/// Storage: `Roles::Roles` (r:2 w:1)
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	fn set_role_membership_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
 	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
 	/// Storage: `Roles::AccountRoles` (r:1 w:0)
 	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
-	/// Storage: `Roles::CallRoles` (r:1 w:0)
-	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
-	fn dispatch_call_with_role() -> Weight {
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	fn invite_to_role() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `220`
-		//  Estimated: `6206`
-		// Minimum execution time: 34_000_000 picoseconds.
-		Weight::from_parts(36_000_000, 0)
-			.saturating_add(Weight::from_parts(0, 6206))
-			.saturating_add(RocksDbWeight::get().reads(4))
-			.saturating_add(RocksDbWeight::get().writes(1))
+		//  Measured:  `173`
+		//  Estimated: `4534`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn accept_role_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `4534`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn decline_role_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn cancel_role_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn remove_expired_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:0 w:1)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentScopes` (r:0 w:1)
+	/// Proof: `Roles::AssignmentScopes` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn renounce_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `244`
+		//  Estimated: `4534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:20 w:20)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:0 w:20)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentScopes` (r:0 w:20)
+	/// Proof: `Roles::AssignmentScopes` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn renounce_all_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2154`
+		//  Estimated: `53650`
+		// Minimum execution time: 231_000_000 picoseconds.
+		Weight::from_parts(236_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 53650))
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().writes(61))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn suspend_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn resume_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::DispatchPause` (r:0 w:1)
+	/// Proof: `Roles::DispatchPause` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
+	fn pause_role_dispatch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().reads(0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::DispatchPause` (r:1 w:1)
+	/// Proof: `Roles::DispatchPause` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
+	fn unpause_role_dispatch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1491`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1491))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRemovals` (r:1 w:1)
+	/// Proof: `Roles::RoleRemovals` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:100)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:100)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:0)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:0)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:0 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:0 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:0 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:0 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleClones` (r:0 w:1)
+	/// Proof: `Roles::RoleClones` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn force_remove_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `302 + n * (148 ±0)`
+		//  Estimated: `4060 + n * (10707 ±0)`
+		// Minimum execution time: 68_000_000 picoseconds.
+		Weight::from_parts(71_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4060))
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:1)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:1)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRemovals` (r:1 w:0)
+	/// Proof: `Roles::RoleRemovals` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleClones` (r:1 w:0)
+	/// Proof: `Roles::RoleClones` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:1 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:1 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:200)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:200)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:0)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:0)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Proposals` (r:1 w:0)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:1 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:1 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn rename_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (148 ±0)`
+		//  Estimated: `4218 + n * (10707 ±0)`
+		// Minimum execution time: 92_000_000 picoseconds.
+		Weight::from_parts(95_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4218))
+			// Standard Error: 14_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(23))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(10))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:1)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:2)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:1 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:1 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:200)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:200)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:0)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:0)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Proposals` (r:1 w:0)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:1 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:1 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn cancel_role_rename(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (148 ±0)`
+		//  Estimated: `4218 + n * (10707 ±0)`
+		// Minimum execution time: 88_000_000 picoseconds.
+		Weight::from_parts(91_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4218))
+			// Standard Error: 14_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(21))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(11))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn update_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:0 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	fn set_role_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `3599`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3599))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn apply_create_role_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3598`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:1)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn apply_add_call_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4511`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:1)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn apply_remove_call_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4511`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn apply_assign_role_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn apply_unassign_role_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166`
+		//  Estimated: `4534`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:13 w:12)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleClones` (r:1 w:1)
+	/// Proof: `Roles::RoleClones` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleCloneTargets` (r:1 w:1)
+	/// Proof: `Roles::RoleCloneTargets` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:1 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:100)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:100)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:5 w:5)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:12 w:11)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:1)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn clone_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296 + n * (148 ±0)`
+		//  Estimated: `66569 + n * (10707 ±0)`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 66569))
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(43))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(33))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn create_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3598`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:0 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:0 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:0 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:0 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	fn remove_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `3598`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:1)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn add_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4511`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:1)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn remove_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4511`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn assign_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4534`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn unassign_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166`
+		//  Estimated: `4534`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn dispatch_call_with_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `220`
+		//  Estimated: `6206`
+		// Minimum execution time: 34_000_000 picoseconds.
+		Weight::from_parts(36_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6206))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:4 w:1)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:0)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleHolders` (r:101 w:0)
+	/// Proof: `Roles::RoleHolders` (`max_values`: None, `max_size`: Some(115), added: 2590, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleGroups` (r:1 w:0)
+	/// Proof: `Roles::RoleGroups` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:100 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:100 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:51 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn add_parent_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312 + n * (121 ±0)`
+		//  Estimated: `168584 + n * (8821 ±0)`
+		// Minimum execution time: 38_000_000 picoseconds.
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 168584))
+			// Standard Error: 9_000
+			.saturating_add(Weight::from_parts(11_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(61))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(Weight::from_parts(0, 8821).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:1)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn remove_parent_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `6206`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6206))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExpiringAssignments` (r:1 w:1)
+	/// Proof: `Roles::ExpiringAssignments` (`max_values`: None, `max_size`: Some(1534), added: 4009, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:0 w:1)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	fn assign_role_with_validity() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4999`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4999))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::AssignmentValidities` (r:1 w:1)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn expire_assignment() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `301`
+		//  Estimated: `4534`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::Roles` (r:3 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:1)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	fn set_role_admin() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `8814`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 8814))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:1)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn add_pallet_wildcard() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4511`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:1)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn remove_pallet_wildcard() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4511`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:0 w:1)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	fn set_call_constraints() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(145), added: 2620, mode: `MaxEncodedLen`)
+	fn set_role_rate_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:0 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:0 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	fn set_role_budget() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `3598`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:1)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn add_role_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4511`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:1)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn remove_role_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `150`
+		//  Estimated: `4511`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::AccountDenials` (r:1 w:1)
+	/// Proof: `Roles::AccountDenials` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn add_account_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3538`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3538))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::AccountDenials` (r:1 w:1)
+	/// Proof: `Roles::AccountDenials` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn remove_account_denial() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `80`
+		//  Estimated: `3538`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3538))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::ActiveEnforcementMode` (r:0 w:1)
+	/// Proof: `Roles::ActiveEnforcementMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_enforcement_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_000_000 picoseconds.
+		Weight::from_parts(7_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::PublicCalls` (r:1 w:1)
+	/// Proof: `Roles::PublicCalls` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn add_public_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3490`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3490))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::PublicCalls` (r:1 w:1)
+	/// Proof: `Roles::PublicCalls` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn remove_public_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3490`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3490))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:2 w:2)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:0)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:51 w:0)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn add_exclusive_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `176`
+		//  Estimated: `163776`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 163776))
+			.saturating_add(RocksDbWeight::get().reads(56))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:2 w:2)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn remove_exclusive_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `290`
+		//  Estimated: `7206`
+		// Minimum execution time: 25_000_000 picoseconds.
+		Weight::from_parts(26_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 7206))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:0 w:1)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	fn set_approval_threshold() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `190`
+		//  Estimated: `4511`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:1 w:0)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountDenials` (r:1 w:0)
+	/// Proof: `Roles::AccountDenials` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:0)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1045), added: 3520, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(308), added: 2783, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:1 w:0)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2138), added: 4613, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:1 w:0)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn propose_call_with_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402`
+		//  Estimated: `4534`
+		// Minimum execution time: 61_000_000 picoseconds.
+		Weight::from_parts(62_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:1 w:0)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(141), added: 2616, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn approve_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1513`
+		//  Estimated: `4795`
+		// Minimum execution time: 29_000_000 picoseconds.
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn cancel_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1446`
+		//  Estimated: `4795`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Proposals` (r:1 w:1)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn remove_expired_proposal() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1446`
+		//  Estimated: `4795`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4795))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn accept_impersonation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn revoke_impersonation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	fn create_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `5120`
+		// Minimum execution time: 10_000_000 picoseconds.
+		Weight::from_parts(11_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	fn remove_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `5120`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:1)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	fn add_group_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `160`
+		//  Estimated: `5120`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::GroupMembers` (r:1 w:1)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:1)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	fn remove_group_member() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `244`
+		//  Estimated: `5120`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5120))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupMembers` (r:1 w:0)
+	/// Proof: `Roles::GroupMembers` (`max_values`: None, `max_size`: Some(1655), added: 4130, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:1)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:5 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:5 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	fn assign_role_to_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1432`
+		//  Estimated: `27715`
+		// Minimum execution time: 96_000_000 picoseconds.
+		Weight::from_parts(98_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 27715))
+			.saturating_add(RocksDbWeight::get().reads(14))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:1)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	fn unassign_role_from_group() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3780`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3780))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentScopes` (r:1 w:1)
+	/// Proof: `Roles::AssignmentScopes` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn assign_role_with_scope() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `173`
+		//  Estimated: `4534`
+		// Minimum execution time: 22_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	fn set_role_membership_rule() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	fn invite_to_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `173`
+		//  Estimated: `4534`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountGroups` (r:1 w:0)
+	/// Proof: `Roles::AccountGroups` (`max_values`: None, `max_size`: Some(212), added: 2687, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn accept_role_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `4534`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn decline_role_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn cancel_role_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Invitations` (r:1 w:1)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn remove_expired_invitation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3598`
+		// Minimum execution time: 15_000_000 picoseconds.
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:0 w:1)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentScopes` (r:0 w:1)
+	/// Proof: `Roles::AssignmentScopes` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn renounce_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `244`
+		//  Estimated: `4534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:20 w:20)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentValidities` (r:0 w:20)
+	/// Proof: `Roles::AssignmentValidities` (`max_values`: None, `max_size`: Some(127), added: 2602, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AssignmentScopes` (r:0 w:20)
+	/// Proof: `Roles::AssignmentScopes` (`max_values`: None, `max_size`: Some(602), added: 3077, mode: `MaxEncodedLen`)
+	fn renounce_all_roles() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2154`
+		//  Estimated: `53650`
+		// Minimum execution time: 231_000_000 picoseconds.
+		Weight::from_parts(236_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 53650))
+			.saturating_add(RocksDbWeight::get().reads(21))
+			.saturating_add(RocksDbWeight::get().writes(61))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn suspend_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn resume_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::DispatchPause` (r:0 w:1)
+	/// Proof: `Roles::DispatchPause` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
+	fn pause_role_dispatch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(RocksDbWeight::get().reads(0))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::DispatchPause` (r:1 w:1)
+	/// Proof: `Roles::DispatchPause` (`max_values`: Some(1), `max_size`: Some(6), added: 501, mode: `MaxEncodedLen`)
+	fn unpause_role_dispatch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1491`
+		// Minimum execution time: 9_000_000 picoseconds.
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1491))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRemovals` (r:1 w:1)
+	/// Proof: `Roles::RoleRemovals` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:100)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:100)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:0)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:0)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:0 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:0 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:0 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:0 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleClones` (r:0 w:1)
+	/// Proof: `Roles::RoleClones` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn force_remove_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `302 + n * (148 ±0)`
+		//  Estimated: `4060 + n * (10707 ±0)`
+		// Minimum execution time: 68_000_000 picoseconds.
+		Weight::from_parts(71_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4060))
+			// Standard Error: 11_000
+			.saturating_add(Weight::from_parts(16_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(15))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(9))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:1)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:1)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRemovals` (r:1 w:0)
+	/// Proof: `Roles::RoleRemovals` (`max_values`: None, `max_size`: Some(583), added: 3058, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleClones` (r:1 w:0)
+	/// Proof: `Roles::RoleClones` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:1 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:1 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:200)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:200)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:0)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:0)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Proposals` (r:1 w:0)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:1 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:1 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn rename_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (148 ±0)`
+		//  Estimated: `4218 + n * (10707 ±0)`
+		// Minimum execution time: 92_000_000 picoseconds.
+		Weight::from_parts(95_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4218))
+			// Standard Error: 14_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(23))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(10))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::Roles` (r:2 w:2)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:1)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:2)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:1 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:1 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:200)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:200)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Invitations` (r:1 w:0)
+	/// Proof: `Roles::Invitations` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:0)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Proposals` (r:1 w:0)
+	/// Proof: `Roles::Proposals` (`max_values`: None, `max_size`: Some(1397), added: 3872, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:1 w:0)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleSpending` (r:1 w:1)
+	/// Proof: `Roles::RoleSpending` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleUsage` (r:1 w:1)
+	/// Proof: `Roles::RoleUsage` (`max_values`: None, `max_size`: Some(75), added: 2550, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:1 w:1)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoleUsage` (r:1 w:0)
+	/// Proof: `Roles::AccountRoleUsage` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn cancel_role_rename(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512 + n * (148 ±0)`
+		//  Estimated: `4218 + n * (10707 ±0)`
+		// Minimum execution time: 88_000_000 picoseconds.
+		Weight::from_parts(91_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4218))
+			// Standard Error: 14_000
+			.saturating_add(Weight::from_parts(22_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(21))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(11))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
+	}
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	fn update_role() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `4099`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4099))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:0)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RolesMetadata` (r:0 w:1)
+	/// Proof: `Roles::RolesMetadata` (`max_values`: None, `max_size`: Some(753), added: 3228, mode: `MaxEncodedLen`)
+	fn set_role_metadata() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `122`
+		//  Estimated: `3599`
+		// Minimum execution time: 12_000_000 picoseconds.
+		Weight::from_parts(13_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3599))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	fn apply_create_role_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4`
+		//  Estimated: `3598`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3598))
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:1)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn apply_add_call_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4511`
+		// Minimum execution time: 20_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:1 w:1)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	fn apply_remove_call_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `4511`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(21_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4511))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn apply_assign_role_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `88`
+		//  Estimated: `4534`
+		// Minimum execution time: 19_000_000 picoseconds.
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:1 w:1)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:1)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	fn apply_unassign_role_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `166`
+		//  Estimated: `4534`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(22_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4534))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	/// Storage: `Roles::Roles` (r:13 w:12)
+	/// Proof: `Roles::Roles` (`max_values`: None, `max_size`: Some(134), added: 2609, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleClones` (r:1 w:1)
+	/// Proof: `Roles::RoleClones` (`max_values`: None, `max_size`: Some(635), added: 3110, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenames` (r:1 w:0)
+	/// Proof: `Roles::RoleRenames` (`max_values`: None, `max_size`: Some(634), added: 3109, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleRenameSources` (r:1 w:0)
+	/// Proof: `Roles::RoleRenameSources` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleCloneTargets` (r:1 w:1)
+	/// Proof: `Roles::RoleCloneTargets` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleBudgets` (r:1 w:1)
+	/// Proof: `Roles::RoleBudgets` (`max_values`: None, `max_size`: Some(87), added: 2562, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleParents` (r:1 w:0)
+	/// Proof: `Roles::RoleParents` (`max_values`: None, `max_size`: Some(281), added: 2756, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::AccountRoles` (r:1 w:0)
+	/// Proof: `Roles::AccountRoles` (`max_values`: None, `max_size`: Some(1069), added: 3544, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallRoles` (r:101 w:100)
+	/// Proof: `Roles::CallRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallConstraints` (r:100 w:100)
+	/// Proof: `Roles::CallConstraints` (`max_values`: None, `max_size`: Some(2155), added: 4630, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ApprovalThresholds` (r:100 w:100)
+	/// Proof: `Roles::ApprovalThresholds` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::CallDeniedRoles` (r:1 w:0)
+	/// Proof: `Roles::CallDeniedRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::PalletRoles` (r:1 w:0)
+	/// Proof: `Roles::PalletRoles` (`max_values`: None, `max_size`: Some(1046), added: 3521, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::GroupRoles` (r:1 w:0)
+	/// Proof: `Roles::GroupRoles` (`max_values`: None, `max_size`: Some(315), added: 2790, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleChildren` (r:5 w:5)
+	/// Proof: `Roles::RoleChildren` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::ExclusiveRoles` (r:12 w:11)
+	/// Proof: `Roles::ExclusiveRoles` (`max_values`: None, `max_size`: Some(595), added: 3070, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleAdmins` (r:1 w:1)
+	/// Proof: `Roles::RoleAdmins` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Roles::RoleMembershipRules` (r:1 w:1)
+	/// Proof: `Roles::RoleMembershipRules` (`max_values`: Some(1), `max_size`: Some(416), added: 911, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	fn clone_role(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `296 + n * (148 ±0)`
+		//  Estimated: `66569 + n * (10707 ±0)`
+		// Minimum execution time: 52_000_000 picoseconds.
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 66569))
+			// Standard Error: 10_000
+			.saturating_add(Weight::from_parts(17_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(43))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(33))
+			.saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 10707).saturating_mul(n.into()))
 	}
 }
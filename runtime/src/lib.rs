@@ -6,12 +6,16 @@
 #[cfg(feature = "std")]
 include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_system::EnsureRoot;
 use pallet_grandpa::AuthorityId as GrandpaId;
 use pallet_rbac::{
-	primitives::{CallMetadata as RuntimeCallMetadata, ModuleCallIndex},
-	traits::GetCallMetadataIndecies,
+	primitives::{CallMetadata as RuntimeCallMetadata, EnforcementMode, ModuleCallIndex},
+	traits::{
+		CallConstraint, CallSpending, GetCallMetadataIndecies, MembershipRule, ResourceExtractor,
+	},
 };
+use scale_info::TypeInfo;
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
@@ -21,7 +25,7 @@ use sp_runtime::{
 		AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, One, Verify,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, BoundedBTreeSet, BoundedVec, MultiSignature,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -273,16 +277,47 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const RbacEnforcementMode: EnforcementMode = EnforcementMode::DefaultAllow;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_rbac::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_rbac::SubstrateWeight<Runtime>;
 	type ManageOrigin = EnsureRoot<AccountId>;
+	type PauseOrigin = EnsureRoot<AccountId>;
 	type RoleNameLengthLimit = ConstU32<50>;
+	type RoleDescriptionLimit = ConstU32<256>;
+	type RoleUriLimit = ConstU32<128>;
+	type RoleTagLengthLimit = ConstU32<32>;
+	type RoleTagsLimit = ConstU32<8>;
 	type RolesPerAccountLimit = ConstU32<20>;
+	type ScopesPerAssignmentLimit = ConstU32<16>;
 	type RolesPerCallLimit = ConstU32<20>;
+	type ConstraintsPerCallLimit = ConstU32<4>;
+	type ParentRolesLimit = ConstU32<5>;
+	type ChildRolesLimit = ConstU32<10>;
+	type MaxRoleHierarchyDepth = ConstU32<5>;
+	type MaxExpiriesPerBlock = ConstU32<50>;
+	type ExclusiveRolesLimit = ConstU32<10>;
+	type MaxGroupMembers = ConstU32<50>;
+	type GroupsPerAccountLimit = ConstU32<10>;
+	type RolesPerGroupLimit = ConstU32<10>;
+	type InheritanceChecksLimit = ConstU32<100>;
+	type MembershipRules = RbacMembershipRules;
+	type MaxRuleBasedRoles = ConstU32<10>;
+	type RemovalStepsLimit = ConstU32<100>;
+	type MaxPolicyChanges = ConstU32<100>;
 	type CallMetadata = RuntimeCallMetadata;
 	type ExtendedRuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type DefaultEnforcementMode = RbacEnforcementMode;
+	type ProposalDeposit = ConstU128<{ 10 * EXISTENTIAL_DEPOSIT }>;
+	type ProposalTimeout = ConstU32<DAYS>;
+	type InvitationTimeout = ConstU32<{ 7 * DAYS }>;
+	type MaxApprovals = ConstU32<16>;
+	type MaxProposedCallLength = ConstU32<4096>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -324,6 +359,15 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
+/// The name of a role of the roles pallet.
+pub type RoleName = BoundedVec<u8, <Runtime as pallet_rbac::Config>::RoleNameLengthLimit>;
+
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_rbac::migrations::v1::MigrateToV1<Runtime>,
+	pallet_rbac::migrations::v2::MigrateToV2<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -331,6 +375,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -349,6 +394,15 @@ mod benches {
 	);
 }
 
+/// Calls which stay open to everyone when the roles pallet forbids calls without roles, so that
+/// the sudo key keeps control over the chain. Meant to seed the pallet's public calls at genesis.
+pub fn rbac_public_calls() -> Vec<ModuleCallIndex> {
+	pallet_sudo::Call::<Runtime>::get_call_indices()
+		.iter()
+		.map(|call_index| (Sudo::index() as u64, *call_index))
+		.collect()
+}
+
 impl GetCallMetadataIndecies for RuntimeCall {
 	fn get_call_metadata_indicies(&self) -> ModuleCallIndex {
 		match self {
@@ -362,6 +416,84 @@ impl GetCallMetadataIndecies for RuntimeCall {
 	}
 }
 
+/// Restrictions on call's arguments which can be attached to role's permissions.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum RoleCallConstraint {
+	/// Limits the amount moved by a balance transfer.
+	MaxTransferAmount(Balance),
+	/// Allows balance transfers of the given amount only.
+	FixedTransferAmount(Balance),
+	/// Allows balance transfers to the given accounts only.
+	TransferDestinations(BoundedBTreeSet<AccountId, ConstU32<16>>),
+}
+
+impl CallConstraint for RuntimeCall {
+	type Constraint = RoleCallConstraint;
+
+	fn satisfies(&self, constraint: &Self::Constraint) -> bool {
+		let (dest, value) = match self {
+			Self::Balances(BalancesCall::transfer_allow_death { dest, value }) |
+			Self::Balances(BalancesCall::transfer_keep_alive { dest, value }) => (dest, value),
+			_ => return false,
+		};
+		match constraint {
+			RoleCallConstraint::MaxTransferAmount(max_amount) => value <= max_amount,
+			RoleCallConstraint::FixedTransferAmount(amount) => value == amount,
+			RoleCallConstraint::TransferDestinations(accounts) =>
+				matches!(dest, sp_runtime::MultiAddress::Id(id) if accounts.contains(id)),
+		}
+	}
+}
+
+impl CallSpending<Balance> for RuntimeCall {
+	fn spent_amount(&self) -> Balance {
+		match self {
+			Self::Balances(BalancesCall::transfer_allow_death { value, .. }) |
+			Self::Balances(BalancesCall::transfer_keep_alive { value, .. }) |
+			Self::Balances(BalancesCall::force_transfer { value, .. }) => *value,
+			Self::Balances(BalancesCall::transfer_all { .. }) => Balance::max_value(),
+			_ => 0,
+		}
+	}
+}
+
+impl ResourceExtractor for RuntimeCall {
+	type Resource = AccountId;
+
+	fn resource(&self) -> Option<Self::Resource> {
+		let who = match self {
+			Self::Balances(BalancesCall::force_set_balance { who, .. }) |
+			Self::Balances(BalancesCall::force_unreserve { who, .. }) |
+			Self::Balances(BalancesCall::force_transfer { source: who, .. }) => who,
+			_ => return None,
+		};
+		match who {
+			sp_runtime::MultiAddress::Id(id) => Some(id.clone()),
+			_ => None,
+		}
+	}
+}
+
+/// Conditions on account's attributes under which the account holds a role automatically.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum RoleMembershipRule {
+	/// Requires the account's free balance to be at least the given amount.
+	MinFreeBalance(Balance),
+}
+
+pub struct RbacMembershipRules;
+
+impl MembershipRule<AccountId> for RbacMembershipRules {
+	type Rule = RoleMembershipRule;
+
+	fn is_satisfied(rule: &Self::Rule, who: &AccountId) -> bool {
+		match rule {
+			RoleMembershipRule::MinFreeBalance(min_free) =>
+				Balances::free_balance(who) >= *min_free,
+		}
+	}
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -486,6 +618,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_rbac_runtime_api::RbacApi<Block, AccountId, RoleName> for Runtime {
+		fn exclusive_role_violations() -> Vec<(AccountId, RoleName, RoleName)> {
+			Roles::exclusive_role_violations()
+		}
+	}
+
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<Block, Balance> for Runtime {
 		fn query_info(
 			uxt: <Block as BlockT>::Extrinsic,